@@ -1,7 +1,35 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("ULPinBridge111111111111111111111111111111");
 
+pub const MAX_SUPPORTED_CHAINS: usize = 16;
+/// Account schema version written by this build; bump alongside layout changes
+pub const CURRENT_VERSION: u8 = 1;
+/// Pending transfers older than this can be failed by anyone
+pub const TRANSFER_TIMEOUT_SECONDS: i64 = 24 * 60 * 60;
+
+/// Moves escrowed tokens out of the bridge vault, signing as the bridge PDA
+pub fn release_from_vault<'info>(
+    bridge: &Account<'info, Bridge>,
+    bridge_vault: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"bridge".as_ref(), &[bridge.bridge_bump]];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: bridge_vault.to_account_info(),
+        to: to.to_account_info(),
+        authority: bridge.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+    )
+}
+
 #[program]
 pub mod ulpin_bridge {
     use super::*;
@@ -11,27 +39,131 @@ pub mod ulpin_bridge {
         bridge_bump: u8,
     ) -> Result<()> {
         let bridge = &mut ctx.accounts.bridge;
+        bridge.version = CURRENT_VERSION;
         bridge.authority = ctx.accounts.authority.key();
         bridge.bridge_bump = bridge_bump;
         bridge.total_transfers = 0;
         bridge.is_active = true;
+        bridge.min_active_relayers = 0;
+        bridge.relayer_freshness_seconds = 0;
+        bridge.supported_chains = Vec::new();
+        
+        Ok(())
+    }
+
+    pub fn set_relayer_requirements(
+        ctx: Context<UpdateBridgeConfig>,
+        min_active_relayers: u8,
+        relayer_freshness_seconds: i64,
+    ) -> Result<()> {
+        require!(relayer_freshness_seconds >= 0, ErrorCode::InvalidFreshnessWindow);
+        
+        let bridge = &mut ctx.accounts.bridge;
+        bridge.min_active_relayers = min_active_relayers;
+        bridge.relayer_freshness_seconds = relayer_freshness_seconds;
+        
+        Ok(())
+    }
+
+    pub fn set_supported_chains(
+        ctx: Context<UpdateBridgeConfig>,
+        chain_ids: Vec<u16>,
+    ) -> Result<()> {
+        require!(chain_ids.len() <= MAX_SUPPORTED_CHAINS, ErrorCode::TooManySupportedChains);
+        
+        ctx.accounts.bridge.supported_chains = chain_ids;
+        
+        Ok(())
+    }
+
+    pub fn register_relayer(
+        ctx: Context<RegisterRelayer>,
+        relayer: Pubkey,
+    ) -> Result<()> {
+        let relayer_account = &mut ctx.accounts.relayer_account;
+        relayer_account.relayer = relayer;
+        relayer_account.last_heartbeat = 0;
+        
+        emit!(RelayerRegistered { relayer });
         
         Ok(())
     }
 
-    pub fn cross_chain_transfer(
-        ctx: Context<CrossChainTransfer>,
+    pub fn relayer_heartbeat(
+        ctx: Context<RelayerHeartbeat>,
+    ) -> Result<()> {
+        let relayer_account = &mut ctx.accounts.relayer_account;
+        relayer_account.last_heartbeat = Clock::get()?.unix_timestamp;
+        
+        emit!(RelayerHeartbeatRecorded {
+            relayer: relayer_account.relayer,
+            timestamp: relayer_account.last_heartbeat,
+        });
+        
+        Ok(())
+    }
+
+    pub fn cross_chain_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrossChainTransfer<'info>>,
         amount: u64,
+        nonce: u64,
+        dest_chain_id: u16,
+        recipient: [u8; 32],
     ) -> Result<()> {
         require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            ctx.accounts.bridge.supported_chains.contains(&dest_chain_id),
+            ErrorCode::UnsupportedChain
+        );
+        
+        // Nonces must strictly increase per sender, so the first one is 1
+        let sender_nonce = &mut ctx.accounts.sender_nonce;
+        require!(nonce > sender_nonce.last_nonce, ErrorCode::InvalidNonce);
+        sender_nonce.sender = ctx.accounts.sender.key();
+        sender_nonce.last_nonce = nonce;
         
         let bridge = &mut ctx.accounts.bridge;
         let transfer = &mut ctx.accounts.transfer;
         
+        // Relayer accounts are passed in remaining_accounts; only distinct
+        // relayers with a heartbeat inside the freshness window are counted.
+        if bridge.min_active_relayers > 0 {
+            let now = Clock::get()?.unix_timestamp;
+            let mut active_relayers: Vec<Pubkey> = Vec::new();
+            for account_info in ctx.remaining_accounts.iter() {
+                let relayer_account = Account::<Relayer>::try_from(account_info)?;
+                if now - relayer_account.last_heartbeat <= bridge.relayer_freshness_seconds
+                    && !active_relayers.contains(&relayer_account.relayer)
+                {
+                    active_relayers.push(relayer_account.relayer);
+                }
+            }
+            require!(
+                active_relayers.len() >= bridge.min_active_relayers as usize,
+                ErrorCode::InsufficientRelayers
+            );
+        }
+        
         transfer.amount = amount;
         transfer.sender = ctx.accounts.sender.key();
         transfer.timestamp = Clock::get()?.unix_timestamp;
         transfer.status = TransferStatus::Pending;
+        transfer.amount_settled = 0;
+        transfer.nonce = nonce;
+        transfer.dest_chain_id = dest_chain_id;
+        transfer.recipient = recipient;
+        transfer.mint = ctx.accounts.mint.key();
+        
+        // Tokens stay in the bridge vault until the transfer is confirmed or refunded
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.from.to_account_info(),
+            to: ctx.accounts.bridge_vault.to_account_info(),
+            authority: ctx.accounts.sender.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            amount,
+        )?;
         
         bridge.total_transfers += 1;
         
@@ -39,6 +171,9 @@ pub mod ulpin_bridge {
             amount: transfer.amount,
             sender: transfer.sender,
             transfer_id: transfer.key(),
+            nonce,
+            dest_chain_id,
+            recipient,
         });
         
         Ok(())
@@ -51,14 +186,126 @@ pub mod ulpin_bridge {
         
         require!(transfer.status == TransferStatus::Pending, ErrorCode::TransferNotPending);
         
+        // Release whatever earlier partial settlements have not already paid out
+        release_from_vault(
+            &ctx.accounts.bridge,
+            &ctx.accounts.bridge_vault,
+            &ctx.accounts.release_account,
+            &ctx.accounts.token_program,
+            transfer.amount - transfer.amount_settled,
+        )?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        transfer.amount_settled = transfer.amount;
         transfer.status = TransferStatus::Completed;
-        transfer.confirmation_timestamp = Clock::get()?.unix_timestamp;
+        transfer.confirmation_timestamp = Some(now);
         
         emit!(CrossChainTransferCompleted {
             transfer_id: transfer.key(),
-            completion_timestamp: transfer.confirmation_timestamp,
+            completion_timestamp: now,
+            relayer: ctx.accounts.relayer.key(),
+        });
+        
+        Ok(())
+    }
+
+    pub fn fail_transfer(
+        ctx: Context<FailTransfer>,
+        reason_code: u8,
+    ) -> Result<()> {
+        let transfer = &mut ctx.accounts.transfer;
+        
+        require!(transfer.status == TransferStatus::Pending, ErrorCode::TransferNotPending);
+        
+        // Relayers (whose account is seeded by the signer) can fail a transfer
+        // at any time; anyone else has to wait for it to time out.
+        if ctx.accounts.relayer_account.is_none() {
+            require!(
+                Clock::get()?.unix_timestamp >= transfer.timestamp + TRANSFER_TIMEOUT_SECONDS,
+                ErrorCode::TransferNotTimedOut
+            );
+        }
+        
+        transfer.status = TransferStatus::Failed;
+        
+        emit!(CrossChainTransferFailed {
+            transfer_id: transfer.key(),
+            reason_code,
+        });
+        
+        Ok(())
+    }
+
+    /// Returns a failed transfer's unsettled tokens to the sender and closes it
+    pub fn refund_transfer(ctx: Context<RefundTransfer>) -> Result<()> {
+        let transfer = &ctx.accounts.transfer;
+        
+        require!(transfer.status == TransferStatus::Failed, ErrorCode::TransferNotRefundable);
+        
+        let amount = transfer.amount - transfer.amount_settled;
+        release_from_vault(
+            &ctx.accounts.bridge,
+            &ctx.accounts.bridge_vault,
+            &ctx.accounts.sender_token_account,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        
+        emit!(CrossChainTransferRefunded {
+            transfer_id: transfer.key(),
+            sender: transfer.sender,
+            amount,
+        });
+        
+        Ok(())
+    }
+
+    pub fn record_partial_settlement(
+        ctx: Context<RecordPartialSettlement>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let transfer = &mut ctx.accounts.transfer;
+        
+        require!(transfer.status == TransferStatus::Pending, ErrorCode::TransferNotPending);
+        
+        let amount_settled = transfer
+            .amount_settled
+            .checked_add(amount)
+            .ok_or(ErrorCode::SettlementExceedsAmount)?;
+        require!(amount_settled <= transfer.amount, ErrorCode::SettlementExceedsAmount);
+        
+        transfer.amount_settled = amount_settled;
+        
+        release_from_vault(
+            &ctx.accounts.bridge,
+            &ctx.accounts.bridge_vault,
+            &ctx.accounts.release_account,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        
+        emit!(PartialSettlementRecorded {
+            transfer_id: transfer.key(),
+            relayer: ctx.accounts.relayer.key(),
+            amount,
+            amount_settled,
         });
         
+        // The transfer only completes once every tranche has been confirmed.
+        if amount_settled == transfer.amount {
+            let now = Clock::get()?.unix_timestamp;
+            transfer.status = TransferStatus::Completed;
+            transfer.confirmation_timestamp = Some(now);
+            
+            emit!(CrossChainTransferCompleted {
+                transfer_id: transfer.key(),
+                completion_timestamp: now,
+                relayer: ctx.accounts.relayer.key(),
+            });
+        }
+        
         Ok(())
     }
 }
@@ -68,7 +315,7 @@ pub struct InitializeBridge<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 1 + 8 + 1 + 32,
+        space = 8 + 32 + 1 + 8 + 1 + 1 + 8 + 4 + MAX_SUPPORTED_CHAINS * 2 + 1 + 32,
         seeds = [b"bridge"],
         bump
     )]
@@ -79,19 +326,81 @@ pub struct InitializeBridge<'info> {
 }
 
 #[derive(Accounts)]
+pub struct UpdateBridgeConfig<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub bridge: Account<'info, Bridge>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(relayer: Pubkey)]
+pub struct RegisterRelayer<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 8,
+        seeds = [b"relayer", relayer.as_ref()],
+        bump
+    )]
+    pub relayer_account: Account<'info, Relayer>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub bridge: Account<'info, Bridge>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RelayerHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer", relayer.key().as_ref()],
+        bump
+    )]
+    pub relayer_account: Account<'info, Relayer>,
+    pub relayer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
 pub struct CrossChainTransfer<'info> {
     #[account(
         init,
         payer = sender,
-        space = 8 + 8 + 32 + 8 + 1 + 8 + 32,
-        seeds = [b"transfer", sender.key().as_ref()],
+        space = 8 + 8 + 32 + 8 + 1 + 9 + 8 + 8 + 2 + 32 + 32 + 32,
+        seeds = [b"transfer", sender.key().as_ref(), &nonce.to_le_bytes()],
         bump
     )]
     pub transfer: Account<'info, CrossChainTransferData>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = 8 + 32 + 8,
+        seeds = [b"sender_nonce", sender.key().as_ref()],
+        bump
+    )]
+    pub sender_nonce: Account<'info, SenderNonce>,
     #[account(mut)]
     pub bridge: Account<'info, Bridge>,
     #[account(mut)]
     pub sender: Signer<'info>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender
+    )]
+    pub from: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        token::mint = mint,
+        token::authority = bridge,
+        seeds = [b"bridge_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub bridge_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
@@ -101,7 +410,86 @@ pub struct ConfirmTransfer<'info> {
     pub transfer: Account<'info, CrossChainTransferData>,
     #[account(mut)]
     pub bridge: Account<'info, Bridge>,
-    pub authority: Signer<'info>,
+    /// Only the bridge authority can create relayer accounts, so a matching
+    /// one proves the signer is an authorized relayer
+    #[account(constraint = relayer_account.relayer == relayer.key() @ ErrorCode::UnauthorizedRelayer)]
+    pub relayer_account: Account<'info, Relayer>,
+    pub relayer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bridge_vault", transfer.mint.as_ref()],
+        bump
+    )]
+    pub bridge_vault: Account<'info, TokenAccount>,
+    /// Confirmed funds go to the bridge authority, which backs the tokens
+    /// issued on the destination chain
+    #[account(
+        mut,
+        constraint = release_account.owner == bridge.authority @ ErrorCode::InvalidReleaseAccount,
+        constraint = release_account.mint == transfer.mint @ ErrorCode::InvalidReleaseAccount
+    )]
+    pub release_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FailTransfer<'info> {
+    #[account(mut)]
+    pub transfer: Account<'info, CrossChainTransferData>,
+    #[account(
+        seeds = [b"relayer", signer.key().as_ref()],
+        bump
+    )]
+    pub relayer_account: Option<Account<'info, Relayer>>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundTransfer<'info> {
+    #[account(mut, has_one = sender, close = sender)]
+    pub transfer: Account<'info, CrossChainTransferData>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub bridge: Account<'info, Bridge>,
+    #[account(
+        mut,
+        seeds = [b"bridge_vault", transfer.mint.as_ref()],
+        bump
+    )]
+    pub bridge_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = bridge_vault.mint,
+        token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RecordPartialSettlement<'info> {
+    #[account(mut)]
+    pub transfer: Account<'info, CrossChainTransferData>,
+    #[account(
+        seeds = [b"relayer", relayer.key().as_ref()],
+        bump
+    )]
+    pub relayer_account: Account<'info, Relayer>,
+    pub relayer: Signer<'info>,
+    pub bridge: Account<'info, Bridge>,
+    #[account(
+        mut,
+        seeds = [b"bridge_vault", transfer.mint.as_ref()],
+        bump
+    )]
+    pub bridge_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = release_account.owner == bridge.authority @ ErrorCode::InvalidReleaseAccount,
+        constraint = release_account.mint == transfer.mint @ ErrorCode::InvalidReleaseAccount
+    )]
+    pub release_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[account]
@@ -110,6 +498,25 @@ pub struct Bridge {
     pub bridge_bump: u8,
     pub total_transfers: u64,
     pub is_active: bool,
+    pub min_active_relayers: u8,
+    pub relayer_freshness_seconds: i64,
+    /// Destination chain ids transfers may be routed to
+    pub supported_chains: Vec<u16>,
+    /// Schema version; appended so older layouts keep their field offsets
+    pub version: u8,
+}
+
+#[account]
+pub struct Relayer {
+    pub relayer: Pubkey,
+    pub last_heartbeat: i64,
+}
+
+/// Highest transfer nonce used by a sender
+#[account]
+pub struct SenderNonce {
+    pub sender: Pubkey,
+    pub last_nonce: u64,
 }
 
 #[account]
@@ -119,6 +526,11 @@ pub struct CrossChainTransferData {
     pub timestamp: i64,
     pub status: TransferStatus,
     pub confirmation_timestamp: Option<i64>,
+    pub amount_settled: u64,
+    pub nonce: u64,
+    pub dest_chain_id: u16,
+    pub recipient: [u8; 32],
+    pub mint: Pubkey,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -133,12 +545,48 @@ pub struct CrossChainTransferInitiated {
     pub amount: u64,
     pub sender: Pubkey,
     pub transfer_id: Pubkey,
+    pub nonce: u64,
+    pub dest_chain_id: u16,
+    pub recipient: [u8; 32],
 }
 
 #[event]
 pub struct CrossChainTransferCompleted {
     pub transfer_id: Pubkey,
     pub completion_timestamp: i64,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct CrossChainTransferFailed {
+    pub transfer_id: Pubkey,
+    pub reason_code: u8,
+}
+
+#[event]
+pub struct CrossChainTransferRefunded {
+    pub transfer_id: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PartialSettlementRecorded {
+    pub transfer_id: Pubkey,
+    pub relayer: Pubkey,
+    pub amount: u64,
+    pub amount_settled: u64,
+}
+
+#[event]
+pub struct RelayerRegistered {
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct RelayerHeartbeatRecorded {
+    pub relayer: Pubkey,
+    pub timestamp: i64,
 }
 
 #[error_code]
@@ -147,4 +595,26 @@ pub enum ErrorCode {
     InvalidAmount,
     #[msg("Transfer is not in pending status")]
     TransferNotPending,
+    #[msg("Signer is not the bridge authority")]
+    Unauthorized,
+    #[msg("Relayer freshness window cannot be negative")]
+    InvalidFreshnessWindow,
+    #[msg("Not enough relayers have sent a recent heartbeat")]
+    InsufficientRelayers,
+    #[msg("Settlement would exceed the transfer amount")]
+    SettlementExceedsAmount,
+    #[msg("Transfer nonce must be greater than the sender's last nonce")]
+    InvalidNonce,
+    #[msg("Destination chain is not supported by the bridge")]
+    UnsupportedChain,
+    #[msg("Too many supported chains")]
+    TooManySupportedChains,
+    #[msg("Signer is not an authorized relayer")]
+    UnauthorizedRelayer,
+    #[msg("Transfer has not timed out yet")]
+    TransferNotTimedOut,
+    #[msg("Only failed transfers can be refunded")]
+    TransferNotRefundable,
+    #[msg("Release account must be the bridge authority's account for the transfer mint")]
+    InvalidReleaseAccount,
 } 
\ No newline at end of file
@@ -0,0 +1,548 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { UlpinBridge } from "../target/types/ulpin_bridge";
+import { expect } from "chai";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+
+describe("ulpin-bridge", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.UlpinBridge as Program<UlpinBridge>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+
+  let bridgePDA: PublicKey;
+  let bridgeBump: number;
+  const destChainId = 1;
+  const recipient = Array(32).fill(9);
+  let mint: PublicKey;
+  // Token accounts the senders escrow from, keyed by owner
+  const tokenAccounts = new Map<string, PublicKey>();
+
+  const findRelayerPDA = (relayer: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("relayer"), relayer.toBuffer()],
+      program.programId
+    )[0];
+
+  const findTransferPDA = (sender: PublicKey, nonce: number): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer"), sender.toBuffer(), new anchor.BN(nonce).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const findSenderNoncePDA = (sender: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("sender_nonce"), sender.toBuffer()],
+      program.programId
+    )[0];
+
+  const findVaultPDA = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge_vault"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const fundSender = async (owner: PublicKey) => {
+    const tokenAccount = await createAccount(connection, provider.wallet.payer, mint, owner);
+    await mintTo(connection, provider.wallet.payer, mint, tokenAccount, provider.wallet.payer, 1000000);
+    tokenAccounts.set(owner.toString(), tokenAccount);
+  };
+
+  const balanceOf = async (tokenAccount: PublicKey): Promise<number> =>
+    Number((await getAccount(connection, tokenAccount)).amount);
+
+  const transferAccounts = (sender: PublicKey, nonce: number) => ({
+    transfer: findTransferPDA(sender, nonce),
+    senderNonce: findSenderNoncePDA(sender),
+    bridge: bridgePDA,
+    sender,
+    mint,
+    from: tokenAccounts.get(sender.toString()),
+    bridgeVault: findVaultPDA(mint),
+    tokenProgram: TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+  });
+
+  const registerRelayer = async (relayer: PublicKey) => {
+    await program.methods
+      .registerRelayer(relayer)
+      .accounts({
+        relayerAccount: findRelayerPDA(relayer),
+        bridge: bridgePDA,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  };
+
+  const heartbeat = async (relayer: anchor.web3.Keypair) => {
+    await program.methods
+      .relayerHeartbeat()
+      .accounts({
+        relayerAccount: findRelayerPDA(relayer.publicKey),
+        relayer: relayer.publicKey,
+      })
+      .signers([relayer])
+      .rpc();
+  };
+
+  before(async () => {
+    [bridgePDA, bridgeBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bridge")],
+      program.programId
+    );
+    mint = await createMint(connection, provider.wallet.payer, provider.wallet.publicKey, null, 6);
+    await fundSender(provider.wallet.publicKey);
+  });
+
+  it("Initializes bridge", async () => {
+    await program.methods
+      .initializeBridge(bridgeBump)
+      .accounts({
+        bridge: bridgePDA,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const bridge = await program.account.bridge.fetch(bridgePDA);
+    expect(bridge.authority.toString()).to.equal(provider.wallet.publicKey.toString());
+    expect(bridge.totalTransfers.toNumber()).to.equal(0);
+    expect(bridge.isActive).to.be.true;
+    expect(bridge.version).to.equal(1);
+  });
+
+  it("Configures supported destination chains", async () => {
+    await program.methods
+      .setSupportedChains([destChainId])
+      .accounts({ bridge: bridgePDA, authority: provider.wallet.publicKey })
+      .rpc();
+
+    const bridge = await program.account.bridge.fetch(bridgePDA);
+    expect(bridge.supportedChains).to.deep.equal([destChainId]);
+  });
+
+  describe("relayer liveness", () => {
+    const relayerA = anchor.web3.Keypair.generate();
+    const relayerB = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await registerRelayer(relayerA.publicKey);
+      await registerRelayer(relayerB.publicKey);
+      await program.methods
+        .setRelayerRequirements(2, new anchor.BN(60))
+        .accounts({ bridge: bridgePDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setRelayerRequirements(0, new anchor.BN(0))
+        .accounts({ bridge: bridgePDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    const relayerAccounts = () =>
+      [relayerA, relayerB].map((relayer) => ({
+        pubkey: findRelayerPDA(relayer.publicKey),
+        isWritable: false,
+        isSigner: false,
+      }));
+
+    it("Rejects transfers when too few relayers are active", async () => {
+      await heartbeat(relayerA);
+
+      try {
+        await program.methods
+          .crossChainTransfer(new anchor.BN(1000), new anchor.BN(1), destChainId, recipient)
+          .accounts(transferAccounts(provider.wallet.publicKey, 1))
+          .remainingAccounts(relayerAccounts())
+          .rpc();
+        expect.fail("Should have required more active relayers");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientRelayers");
+      }
+    });
+
+    it("Accepts transfers once enough relayers are active", async () => {
+      await heartbeat(relayerB);
+
+      await program.methods
+        .crossChainTransfer(new anchor.BN(1000), new anchor.BN(1), destChainId, recipient)
+        .accounts(transferAccounts(provider.wallet.publicKey, 1))
+        .remainingAccounts(relayerAccounts())
+        .rpc();
+
+      const transfer = await program.account.crossChainTransferData.fetch(
+        findTransferPDA(provider.wallet.publicKey, 1)
+      );
+      expect(transfer.amount.toNumber()).to.equal(1000);
+    });
+  });
+
+  describe("partial settlement", () => {
+    const relayer = anchor.web3.Keypair.generate();
+    const sender = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await registerRelayer(relayer.publicKey);
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+
+      await program.methods
+        .crossChainTransfer(new anchor.BN(1000), new anchor.BN(1), destChainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, 1))
+        .signers([sender])
+        .rpc();
+    });
+
+    const settle = (amount: number) =>
+      program.methods
+        .recordPartialSettlement(new anchor.BN(amount))
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 1),
+          relayerAccount: findRelayerPDA(relayer.publicKey),
+          relayer: relayer.publicKey,
+          bridge: bridgePDA,
+          bridgeVault: findVaultPDA(mint),
+          releaseAccount: tokenAccounts.get(provider.wallet.publicKey.toString()),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([relayer])
+        .rpc();
+
+    it("Completes the transfer only once fully settled", async () => {
+      await settle(400);
+
+      let transfer = await program.account.crossChainTransferData.fetch(
+        findTransferPDA(sender.publicKey, 1)
+      );
+      expect(transfer.amountSettled.toNumber()).to.equal(400);
+      expect(transfer.status).to.deep.equal({ pending: {} });
+
+      await settle(600);
+
+      transfer = await program.account.crossChainTransferData.fetch(
+        findTransferPDA(sender.publicKey, 1)
+      );
+      expect(transfer.amountSettled.toNumber()).to.equal(1000);
+      expect(transfer.status).to.deep.equal({ completed: {} });
+    });
+
+    it("Rejects settlement once the transfer has completed", async () => {
+      try {
+        await settle(1);
+        expect.fail("Should have rejected settlement of a completed transfer");
+      } catch (error) {
+        expect(error.message).to.include("TransferNotPending");
+      }
+    });
+  });
+
+  describe("transfer nonces", () => {
+    const sender = anchor.web3.Keypair.generate();
+
+    const transferWithNonce = (nonce: number) =>
+      program.methods
+        .crossChainTransfer(new anchor.BN(500), new anchor.BN(nonce), destChainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, nonce))
+        .signers([sender])
+        .rpc();
+
+    before(async () => {
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+    });
+
+    it("Allows concurrent transfers with increasing nonces", async () => {
+      await transferWithNonce(1);
+      await transferWithNonce(5);
+
+      const first = await program.account.crossChainTransferData.fetch(findTransferPDA(sender.publicKey, 1));
+      const second = await program.account.crossChainTransferData.fetch(findTransferPDA(sender.publicKey, 5));
+      expect(first.nonce.toNumber()).to.equal(1);
+      expect(second.nonce.toNumber()).to.equal(5);
+      expect(second.status).to.deep.equal({ pending: {} });
+    });
+
+    it("Rejects a nonce that does not exceed the last one", async () => {
+      try {
+        await transferWithNonce(3);
+        expect.fail("Should have rejected a stale nonce");
+      } catch (error) {
+        expect(error.message).to.include("InvalidNonce");
+      }
+    });
+  });
+
+  describe("transfer destination", () => {
+    const sender = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+    });
+
+    const transferTo = (chainId: number, nonce: number) =>
+      program.methods
+        .crossChainTransfer(new anchor.BN(500), new anchor.BN(nonce), chainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, nonce))
+        .signers([sender])
+        .rpc();
+
+    it("Records the destination chain and recipient", async () => {
+      await transferTo(destChainId, 1);
+
+      const transfer = await program.account.crossChainTransferData.fetch(findTransferPDA(sender.publicKey, 1));
+      expect(transfer.destChainId).to.equal(destChainId);
+      expect(transfer.recipient).to.deep.equal(recipient);
+    });
+
+    it("Rejects transfers to unsupported chains", async () => {
+      try {
+        await transferTo(999, 2);
+        expect.fail("Should have rejected an unsupported chain");
+      } catch (error) {
+        expect(error.message).to.include("UnsupportedChain");
+      }
+    });
+  });
+
+  describe("transfer confirmation", () => {
+    const relayer = anchor.web3.Keypair.generate();
+    const sender = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await registerRelayer(relayer.publicKey);
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+
+      await program.methods
+        .crossChainTransfer(new anchor.BN(1000), new anchor.BN(1), destChainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, 1))
+        .signers([sender])
+        .rpc();
+    });
+
+    const confirm = (signer: anchor.web3.Keypair) =>
+      program.methods
+        .confirmTransfer()
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 1),
+          bridge: bridgePDA,
+          relayerAccount: findRelayerPDA(relayer.publicKey),
+          relayer: signer.publicKey,
+          bridgeVault: findVaultPDA(mint),
+          releaseAccount: tokenAccounts.get(provider.wallet.publicKey.toString()),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    it("Rejects confirmation by a non-relayer", async () => {
+      try {
+        await confirm(anchor.web3.Keypair.generate());
+        expect.fail("Should have rejected an unauthorized relayer");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedRelayer");
+      }
+    });
+
+    it("Completes the transfer when a relayer confirms", async () => {
+      await confirm(relayer);
+
+      const transfer = await program.account.crossChainTransferData.fetch(
+        findTransferPDA(sender.publicKey, 1)
+      );
+      expect(transfer.status).to.deep.equal({ completed: {} });
+    });
+  });
+
+  describe("failed transfers", () => {
+    const relayer = anchor.web3.Keypair.generate();
+    const sender = anchor.web3.Keypair.generate();
+
+    const initiate = (nonce: number) =>
+      program.methods
+        .crossChainTransfer(new anchor.BN(1000), new anchor.BN(nonce), destChainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, nonce))
+        .signers([sender])
+        .rpc();
+
+    const refund = (nonce: number) =>
+      program.methods
+        .refundTransfer()
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, nonce),
+          sender: sender.publicKey,
+          bridge: bridgePDA,
+          bridgeVault: findVaultPDA(mint),
+          senderTokenAccount: tokenAccounts.get(sender.publicKey.toString()),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sender])
+        .rpc();
+
+    before(async () => {
+      await registerRelayer(relayer.publicKey);
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+      await initiate(1);
+      await initiate(2);
+    });
+
+    it("Rejects failing a fresh transfer without a relayer", async () => {
+      try {
+        await program.methods
+          .failTransfer(1)
+          .accounts({
+            transfer: findTransferPDA(sender.publicKey, 1),
+            relayerAccount: null,
+            signer: sender.publicKey,
+          })
+          .signers([sender])
+          .rpc();
+        expect.fail("Should have required the transfer to time out");
+      } catch (error) {
+        expect(error.message).to.include("TransferNotTimedOut");
+      }
+    });
+
+    it("Refunds a transfer failed by a relayer", async () => {
+      await program.methods
+        .failTransfer(7)
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 1),
+          relayerAccount: findRelayerPDA(relayer.publicKey),
+          signer: relayer.publicKey,
+        })
+        .signers([relayer])
+        .rpc();
+
+      const transfer = await program.account.crossChainTransferData.fetch(
+        findTransferPDA(sender.publicKey, 1)
+      );
+      expect(transfer.status).to.deep.equal({ failed: {} });
+
+      await refund(1);
+      const closed = await provider.connection.getAccountInfo(findTransferPDA(sender.publicKey, 1));
+      expect(closed).to.be.null;
+    });
+
+    it("Rejects refunding a transfer that has not failed", async () => {
+      try {
+        await refund(2);
+        expect.fail("Should have rejected refunding a pending transfer");
+      } catch (error) {
+        expect(error.message).to.include("TransferNotRefundable");
+      }
+    });
+  });
+
+  describe("token custody", () => {
+    const relayer = anchor.web3.Keypair.generate();
+    const sender = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await registerRelayer(relayer.publicKey);
+      const signature = await provider.connection.requestAirdrop(
+        sender.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+      await fundSender(sender.publicKey);
+    });
+
+    const initiate = (nonce: number) =>
+      program.methods
+        .crossChainTransfer(new anchor.BN(2500), new anchor.BN(nonce), destChainId, recipient)
+        .accounts(transferAccounts(sender.publicKey, nonce))
+        .signers([sender])
+        .rpc();
+
+    it("Escrows tokens in the vault and releases them on confirmation", async () => {
+      const vault = findVaultPDA(mint);
+      const senderAccount = tokenAccounts.get(sender.publicKey.toString());
+      const releaseAccount = tokenAccounts.get(provider.wallet.publicKey.toString());
+      const vaultBefore = await balanceOf(vault);
+      const releaseBefore = await balanceOf(releaseAccount);
+
+      await initiate(1);
+      expect(await balanceOf(vault)).to.equal(vaultBefore + 2500);
+      expect(await balanceOf(senderAccount)).to.equal(1000000 - 2500);
+
+      await program.methods
+        .confirmTransfer()
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 1),
+          bridge: bridgePDA,
+          relayerAccount: findRelayerPDA(relayer.publicKey),
+          relayer: relayer.publicKey,
+          bridgeVault: vault,
+          releaseAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([relayer])
+        .rpc();
+
+      expect(await balanceOf(vault)).to.equal(vaultBefore);
+      expect(await balanceOf(releaseAccount)).to.equal(releaseBefore + 2500);
+    });
+
+    it("Returns escrowed tokens to the sender on refund", async () => {
+      const vault = findVaultPDA(mint);
+      const senderAccount = tokenAccounts.get(sender.publicKey.toString());
+      await initiate(2);
+      const vaultBefore = await balanceOf(vault);
+      const senderBefore = await balanceOf(senderAccount);
+
+      await program.methods
+        .failTransfer(1)
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 2),
+          relayerAccount: findRelayerPDA(relayer.publicKey),
+          signer: relayer.publicKey,
+        })
+        .signers([relayer])
+        .rpc();
+      await program.methods
+        .refundTransfer()
+        .accounts({
+          transfer: findTransferPDA(sender.publicKey, 2),
+          sender: sender.publicKey,
+          bridge: bridgePDA,
+          bridgeVault: vault,
+          senderTokenAccount: senderAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sender])
+        .rpc();
+
+      expect(await balanceOf(vault)).to.equal(vaultBefore - 2500);
+      expect(await balanceOf(senderAccount)).to.equal(senderBefore + 2500);
+    });
+  });
+});
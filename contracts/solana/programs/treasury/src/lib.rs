@@ -1,10 +1,444 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
-pub mod ulpin_freeze;
-use ulpin_freeze::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::Discriminator;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer, Burn};
+use anchor_spl::token_2022::{self, Token2022, MintTo, SetAuthority};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{self, TokenInterface, FreezeAccount, ThawAccount};
 
 declare_id!("ULPinTreasury111111111111111111111111111111");
 
+pub const MAX_DISBURSEMENTS_PER_BATCH: usize = 10;
+pub const MAX_BOUNDARY_POINTS: usize = 32;
+pub const MIN_BOUNDARY_POINTS: usize = 3;
+pub const MAX_QUARANTINE_REASON_LEN: usize = 128;
+pub const MAX_SANCTIONED_PARTIES: usize = 100;
+pub const MAX_HOLDINGS_ACCOUNTS: usize = 16;
+pub const MAX_PORTFOLIO_PARCELS: usize = 16;
+pub const MAX_EASEMENTS: usize = 8;
+pub const MAX_TRACKED_TRANSFERS: usize = 8;
+pub const MAX_REGISTRATION_BATCH: usize = 5;
+pub const MAX_SUBDIVISION_CHILDREN: usize = 5;
+pub const MAX_MERGE_SOURCES: usize = 5;
+pub const MAX_VERIFICATION_BATCH: usize = 10;
+pub const MAX_THAW_BATCH: usize = 8;
+pub const MAX_VERIFIERS: usize = 10;
+pub const MAX_METADATA_URI_LEN: usize = 200;
+pub const OWNERSHIP_HISTORY_PAGE_SIZE: usize = 16;
+pub const MAX_DISPUTE_REASON_LEN: usize = 128;
+/// Account schema version written by this build; bump alongside layout changes
+pub const CURRENT_VERSION: u8 = 5;
+pub const MAX_ACTIVE_LIENS: u8 = 4;
+pub const MAX_FORCE_THAW_REASON_LEN: usize = 128;
+pub const MAX_FREEZE_NOTE_LEN: usize = 200;
+/// Longest freeze allowed until the freeze authority configures otherwise
+pub const DEFAULT_MAX_FREEZE_SECONDS: i64 = 5 * 365 * 24 * 60 * 60;
+
+// Gujarat's extent with some margin, in degrees scaled by 1e6
+pub const GUJARAT_MIN_LAT: i32 = 20_000_000;
+pub const GUJARAT_MAX_LAT: i32 = 24_800_000;
+pub const GUJARAT_MIN_LON: i32 = 68_000_000;
+pub const GUJARAT_MAX_LON: i32 = 74_600_000;
+/// Mint fees charged until a `FeeConfig` account is initialized
+pub const DEFAULT_BASE_FEE: u64 = 100_000; // 0.0001 SOL in lamports
+pub const DEFAULT_PER_SQM_FEE: u64 = 10;
+pub const DEFAULT_CLASS_MULTIPLIER_BPS: u16 = 10_000;
+pub const LAND_USE_CLASSES: usize = 6;
+
+/// Bits of `Treasury::paused_flags`; each pauses one operation independently
+pub const PAUSE_REGISTRATION: u8 = 1 << 0;
+pub const PAUSE_MINTING: u8 = 1 << 1;
+pub const PAUSE_VERIFICATION: u8 = 1 << 2;
+pub const PAUSE_OWNERSHIP_UPDATES: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 =
+    PAUSE_REGISTRATION | PAUSE_MINTING | PAUSE_VERIFICATION | PAUSE_OWNERSHIP_UPDATES;
+
+/// Keccak hash of the full ULPIN, used as the parcel PDA seed so that ids
+/// of any length up to 64 bytes map to a distinct address.
+pub fn ulpin_seed(ulpin_id: &str) -> [u8; 32] {
+    keccak::hash(ulpin_id.as_bytes()).to_bytes()
+}
+
+/// Zero-padded 32-byte form of a district, taluka or village name, matching
+/// how locations are stored on `LandParcel` and used as PDA seeds.
+pub fn location_seed(name: &str) -> [u8; 32] {
+    let mut name_bytes = [0u8; 32];
+    let len = name.len().min(32);
+    name_bytes[..len].copy_from_slice(&name.as_bytes()[..len]);
+    name_bytes
+}
+
+/// Keccak hash of a district's zero-padded name, used to seed its
+/// `DistrictStats` PDA
+pub fn district_stats_seed(district: &[u8; 32]) -> [u8; 32] {
+    keccak::hash(district).to_bytes()
+}
+
+/// Records the parcel's current owner on its active `OwnershipHistory` page.
+/// A full page is never rejected: the next transfer lands on a fresh page PDA.
+pub fn append_ownership_history(
+    history: &mut OwnershipHistory,
+    land_parcel: &mut Account<LandParcel>,
+    ulpin_id: &str,
+) -> Result<()> {
+    let index = land_parcel.ownership_history_count;
+    history.land_parcel = land_parcel.key();
+    history.page = land_parcel.ownership_history_page();
+    history.entries.push(OwnershipHistoryEntry {
+        owner: land_parcel.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    land_parcel.ownership_history_count += 1;
+    
+    emit!(OwnershipHistoryAppended {
+        ulpin_id: ulpin_id.to_string(),
+        owner: land_parcel.owner,
+        page: history.page,
+        index,
+    });
+    
+    Ok(())
+}
+
+/// Moves a parcel to its new owner and runs the post-transfer bookkeeping
+/// shared by immediate and time-locked transfers.
+pub fn apply_ownership_change(
+    land_parcel: &mut Account<LandParcel>,
+    treasury: &Treasury,
+    history: &mut OwnershipHistory,
+    ulpin_id: String,
+    new_owner: Pubkey,
+) -> Result<()> {
+    let previous_owner = land_parcel.owner;
+    land_parcel.owner = new_owner;
+    land_parcel.ownership_type = OwnershipType::Individual;
+    // Any staged change was against the previous owner and no longer applies
+    land_parcel.pending_owner = None;
+    land_parcel.pending_owner_effective_at = 0;
+    land_parcel.pending_owner_staged_from = Pubkey::default();
+    
+    emit!(OwnershipTransferred {
+        ulpin_id: ulpin_id.clone(),
+        previous_owner,
+        new_owner,
+        transfer_timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    append_ownership_history(history, land_parcel, &ulpin_id)?;
+    
+    // Fraud tripwire: too many hand-offs inside the window put the parcel on hold for review
+    let now = Clock::get()?.unix_timestamp;
+    let oldest = (0..MAX_TRACKED_TRANSFERS)
+        .min_by_key(|&i| land_parcel.recent_transfers[i])
+        .unwrap_or_default();
+    land_parcel.recent_transfers[oldest] = now;
+    if let Some(limit) = &treasury.rapid_transfer_limit {
+        let transfer_count = land_parcel
+            .recent_transfers
+            .iter()
+            .filter(|&&ts| ts > 0 && now - ts <= limit.window_seconds)
+            .count() as u8;
+        if transfer_count > limit.max_transfers {
+            land_parcel.quarantine_until = now + limit.review_seconds;
+            
+            emit!(AutoFreezeTriggered {
+                ulpin_id: ulpin_id.clone(),
+                transfer_count,
+                window_seconds: limit.window_seconds,
+                frozen_until: land_parcel.quarantine_until,
+            });
+        }
+    }
+    
+    if treasury.reverify_on_transfer {
+        land_parcel.is_verified = false;
+        land_parcel.verified_at = None;
+        land_parcel.verified_by = None;
+        
+        emit!(VerificationResetOnTransfer {
+            ulpin_id,
+            new_owner,
+        });
+    }
+    
+    Ok(())
+}
+
+/// Holds and approvals every ownership transfer must clear, whether it is
+/// applied directly, staged behind the transfer delay or settled through
+/// escrow. Callers check who may initiate the transfer and whether one is
+/// already pending.
+#[allow(clippy::too_many_arguments)]
+pub fn check_transfer_allowed(
+    land_parcel: &LandParcel,
+    treasury: &Treasury,
+    sanctions_list: &SanctionsList,
+    village_config_info: &AccountInfo,
+    new_owner: Pubkey,
+    protection_authority: Option<Pubkey>,
+    panchayat: Option<Pubkey>,
+    karta: Option<Pubkey>,
+) -> Result<()> {
+    require!(!treasury.is_paused(PAUSE_OWNERSHIP_UPDATES), ErrorCode::OperationPaused);
+    
+    let now = Clock::get()?.unix_timestamp;
+    require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+    require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+    require!(!land_parcel.is_quarantined(now), ErrorCode::ParcelQuarantined);
+    require!(!land_parcel.is_frozen(now), ErrorCode::ParcelFrozen);
+    
+    // Give indexers time to pick up the mint before the parcel can change hands
+    if let (Some(settlement), Some(minted_at)) = (
+        treasury.mint_settlement_seconds,
+        land_parcel.mint_timestamp,
+    ) {
+        require!(now >= minted_at + settlement as i64, ErrorCode::MintNotSettled);
+    }
+    
+    require!(!land_parcel.under_arbitration, ErrorCode::ParcelUnderArbitration);
+    require!(!land_parcel.under_dispute, ErrorCode::ParcelUnderDispute);
+    require!(land_parcel.active_liens == 0, ErrorCode::ActiveLienExists);
+    require!(!land_parcel.subdivided, ErrorCode::SubdividedParcelNotTransferable);
+    require!(!land_parcel.merged, ErrorCode::MergedParcelNotTransferable);
+    require!(
+        land_parcel.transfer_blocking_easements == 0,
+        ErrorCode::EasementBlocksTransfer
+    );
+    
+    if land_parcel.protected {
+        require!(
+            protection_authority == Some(land_parcel.protection_authority),
+            ErrorCode::ProtectionAuthorityRequired
+        );
+    }
+    
+    if let Some(window) = &land_parcel.transfer_window {
+        require!(
+            now >= window.start_ts && now <= window.end_ts,
+            ErrorCode::OutsideTransferWindow
+        );
+    }
+    
+    require!(!sanctions_list.is_sanctioned(&new_owner), ErrorCode::SanctionedParty);
+    
+    // Villages without a config account impose no panchayat requirement
+    if !village_config_info.data_is_empty() {
+        let village_config = VillageConfig::try_deserialize(
+            &mut &village_config_info.try_borrow_data()?[..]
+        )?;
+        if village_config.requires_panchayat_approval {
+            require!(
+                panchayat == Some(village_config.panchayat_signer),
+                ErrorCode::PanchayatApprovalRequired
+            );
+        }
+    }
+    
+    // Joint-family property can only be alienated on the karta's authority
+    if let OwnershipType::Huf { karta: required_karta } = land_parcel.ownership_type {
+        require!(karta == Some(required_karta), ErrorCode::KartaSignatureRequired);
+        
+        emit!(HufTransferAuthorized {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            karta: required_karta,
+            new_owner,
+        });
+    }
+    
+    Ok(())
+}
+
+/// Emits the generic `ParcelStateChanged` event that indexers follow. Every
+/// instruction that mutates a parcel calls this after its specific event.
+pub fn emit_parcel_state_changed(
+    land_parcel: &LandParcel,
+    change_type: ParcelChangeType,
+    actor: Pubkey,
+) -> Result<()> {
+    emit!(ParcelStateChanged {
+        ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+        change_type,
+        actor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Checks shared by single and batch registration.
+pub fn validate_registration(
+    input: &LandParcelInput,
+    sanctions_list: &SanctionsList,
+    jurisdiction_info: &AccountInfo,
+) -> Result<()> {
+    require!(!input.ulpin_id.is_empty(), ErrorCode::EmptyULPIN);
+    require!(input.ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
+    require!(input.area_sqm > 0, ErrorCode::InvalidArea);
+    require!(
+        input.district.len() <= 32 && input.taluka.len() <= 32 && input.village.len() <= 32,
+        ErrorCode::InvalidLocationName
+    );
+    require!(input.bbox.is_valid(), ErrorCode::InvalidCoordinates);
+    require!(
+        jurisdiction_bounds(jurisdiction_info)?.contains(&input.bbox),
+        ErrorCode::OutsideJurisdiction
+    );
+    require!(
+        !sanctions_list.is_sanctioned(&input.owner),
+        ErrorCode::SanctionedParty
+    );
+    
+    Ok(())
+}
+
+/// Area parcels may be registered in. Without a jurisdiction account the
+/// state of Gujarat applies.
+pub fn jurisdiction_bounds(jurisdiction_info: &AccountInfo) -> Result<BoundingBox> {
+    if jurisdiction_info.data_is_empty() {
+        return Ok(BoundingBox::GUJARAT);
+    }
+    let jurisdiction = JurisdictionConfig::try_deserialize(
+        &mut &jurisdiction_info.try_borrow_data()?[..]
+    )?;
+    Ok(jurisdiction.bounds)
+}
+
+/// Rejects plots below the district's minimum size. Districts without a
+/// rules account only need a non-zero area.
+pub fn check_district_minimum(district_rules_info: &AccountInfo, area_sqm: u64) -> Result<()> {
+    if district_rules_info.data_is_empty() {
+        return Ok(());
+    }
+    let district_rules = DistrictRules::try_deserialize(
+        &mut &district_rules_info.try_borrow_data()?[..]
+    )?;
+    require!(
+        area_sqm >= district_rules.min_area_sqm,
+        ErrorCode::AreaBelowDistrictMinimum
+    );
+    
+    Ok(())
+}
+
+/// A parcel NFT is a zero-decimal mint with exactly one token issued and
+/// the mint authority revoked, so no further copies can exist.
+pub fn is_non_fungible(mint: &Mint) -> bool {
+    mint.decimals == 0 && mint.supply == 1 && mint.mint_authority.is_none()
+}
+
+/// Checks shared by the SPL-token and SOL mint paths. Also counts the mint
+/// against the owner's limit.
+pub fn check_mint_allowed(
+    land_parcel: &LandParcel,
+    treasury: &Treasury,
+    owner_mint_record: &mut OwnerMintRecord,
+    metadata_uri: &str,
+    exemption_bps: u16,
+) -> Result<()> {
+    require!(!treasury.is_paused(PAUSE_MINTING), ErrorCode::OperationPaused);
+    require!(exemption_bps <= 10_000, ErrorCode::InvalidExemption);
+    require!(metadata_uri.len() <= MAX_METADATA_URI_LEN, ErrorCode::InvalidMetadataURI);
+    
+    require!(!land_parcel.nft_minted, ErrorCode::NFTAlreadyMinted);
+    require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+    require!(!land_parcel.under_dispute, ErrorCode::ParcelUnderDispute);
+    require!(land_parcel.active_liens == 0, ErrorCode::ActiveLienExists);
+    require!(
+        !land_parcel.is_quarantined(Clock::get()?.unix_timestamp),
+        ErrorCode::ParcelQuarantined
+    );
+    require!(
+        land_parcel.subsurface_owner != Pubkey::default(),
+        ErrorCode::SubsurfaceOwnerNotSet
+    );
+    
+    owner_mint_record.owner = land_parcel.owner;
+    if let (false, Some(max_nfts)) = (owner_mint_record.exempt, treasury.max_nfts_per_owner) {
+        require!(
+            owner_mint_record.minted_count < max_nfts,
+            ErrorCode::OwnerMintLimitReached
+        );
+    }
+    owner_mint_record.minted_count += 1;
+    
+    Ok(())
+}
+
+/// Area-based mint fee, using the defaults until a fee config exists.
+/// Government and welfare-scheme registrations are discounted only when the
+/// treasury authority co-signs. Returns the fee, the applied exemption and
+/// the discount.
+pub fn mint_fee(
+    area_sqm: u64,
+    land_use: LandUse,
+    fee_config_info: &AccountInfo,
+    exemption_bps: u16,
+    authority_cosigned: bool,
+) -> Result<(u64, u16, u64, u16)> {
+    let (base_fee, per_sqm_fee, class_multiplier_bps) = if fee_config_info.data_is_empty() {
+        (DEFAULT_BASE_FEE, DEFAULT_PER_SQM_FEE, DEFAULT_CLASS_MULTIPLIER_BPS)
+    } else {
+        let fee_config = FeeConfig::try_deserialize(
+            &mut &fee_config_info.try_borrow_data()?[..]
+        )?;
+        (
+            fee_config.base_fee,
+            fee_config.per_sqm_fee,
+            fee_config.class_multipliers_bps[land_use as usize],
+        )
+    };
+    // The land-use multiplier only scales the area component, not the base fee
+    let area_fee = u128::from(area_sqm)
+        .checked_mul(u128::from(per_sqm_fee))
+        .and_then(|fee| fee.checked_mul(u128::from(class_multiplier_bps)))
+        .map(|fee| fee / 10_000)
+        .and_then(|fee| u64::try_from(fee).ok())
+        .ok_or(ErrorCode::FeeOverflow)?;
+    let gross_fee = base_fee.checked_add(area_fee).ok_or(ErrorCode::FeeOverflow)?;
+    
+    let exemption_bps = if authority_cosigned { exemption_bps } else { 0 };
+    let discount = u64::try_from(u128::from(gross_fee) * u128::from(exemption_bps) / 10_000)
+        .map_err(|_| ErrorCode::FeeOverflow)?;
+    let total_fee = gross_fee.checked_sub(discount).ok_or(ErrorCode::FeeOverflow)?;
+    
+    Ok((total_fee, exemption_bps, discount, class_multiplier_bps))
+}
+
+/// Creates a parcel PDA passed through remaining_accounts and writes its
+/// initial state, doing what `init` does for `RegisterLandParcel`.
+pub fn create_land_parcel_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    land_parcel: &LandParcel,
+    ulpin_id: &str,
+) -> Result<()> {
+    let seed = ulpin_seed(ulpin_id);
+    let (expected, bump) = Pubkey::find_program_address(&[b"land_parcel", &seed], program_id);
+    require_keys_eq!(account_info.key(), expected, ErrorCode::InvalidBatchAccounts);
+    
+    let bump_seed = [bump];
+    let signer_seeds: &[&[u8]] = &[b"land_parcel", &seed, &bump_seed];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program_info.clone(),
+            system_program::CreateAccount {
+                from: payer.clone(),
+                to: account_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        Rent::get()?.minimum_balance(LandParcel::SPACE),
+        LandParcel::SPACE as u64,
+        program_id,
+    )?;
+    
+    land_parcel.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])
+}
+
 #[program]
 pub mod ulpin_treasury {
     use super::*;
@@ -14,271 +448,5571 @@ pub mod ulpin_treasury {
         treasury_bump: u8,
     ) -> Result<()> {
         let treasury = &mut ctx.accounts.treasury;
+        treasury.version = CURRENT_VERSION;
         treasury.authority = ctx.accounts.authority.key();
         treasury.treasury_bump = treasury_bump;
         treasury.total_fees_collected = 0;
+        treasury.sol_fees_collected = 0;
+        treasury.total_withdrawn = 0;
+        treasury.current_balance = 0;
+        treasury.transfer_delay_seconds = 0;
+        treasury.vote_validity_seconds = 0;
+        treasury.min_metadata_update_interval = 0;
+        treasury.fee_mint = Pubkey::default();
+        treasury.sol_withdrawn = 0;
+        treasury.agriculture_authority = ctx.accounts.authority.key();
         treasury.land_parcel_count = 0;
         treasury.is_active = true;
+        treasury.owner_ack_required = false;
+        treasury.mint_settlement_seconds = None;
+        treasury.heritage_authority = ctx.accounts.authority.key();
+        treasury.max_nfts_per_owner = None;
+        treasury.reverify_on_transfer = false;
+        treasury.auto_sweep_threshold = None;
+        treasury.enforce_owner_token_account = false;
+        treasury.rapid_transfer_limit = None;
+        treasury.pending_authority = None;
+        treasury.paused_flags = 0;
+        treasury.required_approvals = 0;
+        
+        let sanctions_list = &mut ctx.accounts.sanctions_list;
+        sanctions_list.blocked = Vec::new();
         
         Ok(())
     }
 
-    pub fn register_land_parcel(
-        ctx: Context<RegisterLandParcel>,
-        ulpin_id: String,
-        area_sqm: u64,
-        district: String,
-        taluka: String,
-        village: String,
-        owner_pubkey: Pubkey,
+    pub fn add_sanctioned_party(
+        ctx: Context<UpdateSanctionsList>,
+        party: Pubkey,
     ) -> Result<()> {
-        require!(ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
-        require!(area_sqm > 0, ErrorCode::InvalidArea);
+        let sanctions_list = &mut ctx.accounts.sanctions_list;
         
-        let land_parcel = &mut ctx.accounts.land_parcel;
-        let treasury = &mut ctx.accounts.treasury;
+        if !sanctions_list.is_sanctioned(&party) {
+            require!(
+                sanctions_list.blocked.len() < MAX_SANCTIONED_PARTIES,
+                ErrorCode::SanctionsListFull
+            );
+            sanctions_list.blocked.push(party);
+        }
         
-        // Convert strings to fixed arrays
-        let mut ulpin_bytes = [0u8; 64];
-        let mut district_bytes = [0u8; 32];
-        let mut taluka_bytes = [0u8; 32];
-        let mut village_bytes = [0u8; 32];
-        
-        ulpin_bytes[..ulpin_id.len()].copy_from_slice(ulpin_id.as_bytes());
-        district_bytes[..district.len()].copy_from_slice(district.as_bytes());
-        taluka_bytes[..taluka.len()].copy_from_slice(taluka.as_bytes());
-        village_bytes[..village.len()].copy_from_slice(village.as_bytes());
-        
-        land_parcel.ulpin_id = ulpin_bytes;
-        land_parcel.area_sqm = area_sqm;
-        land_parcel.district = district_bytes;
-        land_parcel.taluka = taluka_bytes;
-        land_parcel.village = village_bytes;
-        land_parcel.owner = owner_pubkey;
-        land_parcel.registration_timestamp = Clock::get()?.unix_timestamp;
-        land_parcel.is_verified = false;
-        land_parcel.nft_minted = false;
+        emit!(SanctionedPartyAdded { party });
         
-        treasury.land_parcel_count += 1;
+        Ok(())
+    }
+
+    pub fn remove_sanctioned_party(
+        ctx: Context<UpdateSanctionsList>,
+        party: Pubkey,
+    ) -> Result<()> {
+        let sanctions_list = &mut ctx.accounts.sanctions_list;
         
-        emit!(LandParcelRegistered {
-            ulpin_id,
-            owner: land_parcel.owner,
-            area_sqm: land_parcel.area_sqm,
-            registration_timestamp: land_parcel.registration_timestamp,
-        });
+        let index = sanctions_list.blocked
+            .iter()
+            .position(|blocked| *blocked == party)
+            .ok_or(ErrorCode::SanctionedPartyNotFound)?;
+        sanctions_list.blocked.swap_remove(index);
+        
+        emit!(SanctionedPartyRemoved { party });
         
         Ok(())
     }
 
-    pub fn mint_land_nft(
-        ctx: Context<MintLandNFT>,
-        ulpin_id: String,
-        metadata_uri: String,
+    pub fn propose_treasury_authority(
+        ctx: Context<UpdateTreasuryConfig>,
+        new_authority: Pubkey,
     ) -> Result<()> {
-        require!(metadata_uri.len() <= 200, ErrorCode::InvalidMetadataURI);
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.pending_authority = Some(new_authority);
         
-        let land_parcel = &mut ctx.accounts.land_parcel;
+        Ok(())
+    }
+
+    pub fn cancel_treasury_authority_proposal(
+        ctx: Context<UpdateTreasuryConfig>,
+    ) -> Result<()> {
         let treasury = &mut ctx.accounts.treasury;
+        require!(treasury.pending_authority.is_some(), ErrorCode::NoPendingAuthority);
+        treasury.pending_authority = None;
         
-        require!(!land_parcel.nft_minted, ErrorCode::NFTAlreadyMinted);
-        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+        Ok(())
+    }
+
+    pub fn accept_treasury_authority(
+        ctx: Context<AcceptTreasuryAuthority>,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        let pending_authority = treasury.pending_authority.ok_or(ErrorCode::NoPendingAuthority)?;
+        require_keys_eq!(
+            ctx.accounts.new_authority.key(),
+            pending_authority,
+            ErrorCode::Unauthorized
+        );
         
-        // Calculate fees based on area
-        let base_fee = 100_000; // 0.0001 SOL in lamports
-        let area_fee = (land_parcel.area_sqm as u64) * 10; // 10 lamports per sqm
-        let total_fee = base_fee + area_fee;
+        let old = treasury.authority;
+        treasury.authority = pending_authority;
+        treasury.pending_authority = None;
         
-        // Transfer fees to treasury
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.treasury_token_account.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, total_fee)?;
+        emit!(TreasuryAuthorityTransferred {
+            old,
+            new: pending_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         
-        land_parcel.nft_minted = true;
-        treasury.total_fees_collected += total_fee;
+        Ok(())
+    }
+
+    pub fn set_paused(
+        ctx: Context<UpdateTreasuryConfig>,
+        flags: u8,
+    ) -> Result<()> {
+        require!(flags & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
         
-        emit!(NFTMinted {
-            ulpin_id: ulpin_id.clone(),
-            owner: land_parcel.owner,
-            nft_mint: ctx.accounts.nft_mint.key(),
-            metadata_uri,
-            fee_paid: total_fee,
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.paused_flags = flags;
+        
+        emit!(TreasuryPauseUpdated {
+            paused_flags: flags,
+            authority: ctx.accounts.authority.key(),
         });
         
         Ok(())
     }
 
-    pub fn verify_land_parcel(
-        ctx: Context<VerifyLandParcel>,
-        ulpin_id: String,
+    pub fn set_owner_ack_required(
+        ctx: Context<UpdateTreasuryConfig>,
+        owner_ack_required: bool,
     ) -> Result<()> {
-        let land_parcel = &mut ctx.accounts.land_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.owner_ack_required = owner_ack_required;
         
-        require!(!land_parcel.is_verified, ErrorCode::AlreadyVerified);
+        Ok(())
+    }
+
+    pub fn set_mint_settlement_seconds(
+        ctx: Context<UpdateTreasuryConfig>,
+        mint_settlement_seconds: Option<u32>,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.mint_settlement_seconds = mint_settlement_seconds;
         
-        land_parcel.is_verified = true;
+        Ok(())
+    }
+
+    pub fn set_heritage_authority(
+        ctx: Context<UpdateTreasuryConfig>,
+        heritage_authority: Pubkey,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.heritage_authority = heritage_authority;
         
-        emit!(LandParcelVerified {
-            ulpin_id: ulpin_id.clone(),
-            verifier: ctx.accounts.authority.key(),
-            verification_timestamp: Clock::get()?.unix_timestamp,
-        });
+        Ok(())
+    }
+
+    pub fn set_agriculture_authority(
+        ctx: Context<UpdateTreasuryConfig>,
+        agriculture_authority: Pubkey,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.agriculture_authority = agriculture_authority;
         
         Ok(())
     }
 
-    pub fn update_land_ownership(
-        ctx: Context<UpdateLandOwnership>,
-        ulpin_id: String,
-        new_owner: Pubkey,
+    pub fn set_max_nfts_per_owner(
+        ctx: Context<UpdateTreasuryConfig>,
+        max_nfts_per_owner: Option<u32>,
     ) -> Result<()> {
-        let land_parcel = &mut ctx.accounts.land_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.max_nfts_per_owner = max_nfts_per_owner;
         
-        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
-        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        Ok(())
+    }
+
+    pub fn set_reverify_on_transfer(
+        ctx: Context<UpdateTreasuryConfig>,
+        reverify_on_transfer: bool,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.reverify_on_transfer = reverify_on_transfer;
         
-        land_parcel.owner = new_owner;
+        Ok(())
+    }
+
+    /// Challenge window for ownership transfers; 0 applies them immediately
+    pub fn set_transfer_delay(
+        ctx: Context<UpdateTreasuryConfig>,
+        transfer_delay_seconds: i64,
+    ) -> Result<()> {
+        require!(transfer_delay_seconds >= 0, ErrorCode::InvalidTransferDelay);
         
-        emit!(OwnershipTransferred {
-            ulpin_id: ulpin_id.clone(),
-            previous_owner: land_parcel.owner,
-            new_owner,
-            transfer_timestamp: Clock::get()?.unix_timestamp,
-        });
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.transfer_delay_seconds = transfer_delay_seconds;
         
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeTreasury<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 1 + 8 + 8 + 1 + 32,
-        seeds = [b"treasury"],
-        bump
-    )]
-    pub treasury: Account<'info, Treasury>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Sets how long a verification vote counts towards the quorum; 0 keeps
+    /// votes valid until the parcel is verified
+    pub fn set_vote_validity(
+        ctx: Context<UpdateTreasuryConfig>,
+        vote_validity_seconds: i64,
+    ) -> Result<()> {
+        require!(vote_validity_seconds >= 0, ErrorCode::InvalidVoteValidity);
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.vote_validity_seconds = vote_validity_seconds;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct RegisterLandParcel<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + 64 + 8 + 32 + 32 + 32 + 32 + 8 + 1 + 1 + 32,
-        seeds = [b"land_parcel", &ulpin_id.as_bytes()[..32]],
-        bump
-    )]
-    pub land_parcel: Account<'info, LandParcel>,
-    #[account(mut)]
-    pub treasury: Account<'info, Treasury>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Sets the minimum gap between metadata updates on a parcel; 0 disables
+    /// the limit
+    pub fn set_min_metadata_update_interval(
+        ctx: Context<UpdateTreasuryConfig>,
+        min_metadata_update_interval: i64,
+    ) -> Result<()> {
+        require!(min_metadata_update_interval >= 0, ErrorCode::InvalidMetadataUpdateInterval);
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.min_metadata_update_interval = min_metadata_update_interval;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct MintLandNFT<'info> {
-    #[account(mut)]
-    pub land_parcel: Account<'info, LandParcel>,
-    #[account(mut)]
-    pub treasury: Account<'info, Treasury>,
-    #[account(mut)]
-    pub nft_mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub treasury_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn set_fee_mint(
+        ctx: Context<UpdateTreasuryConfig>,
+        fee_mint: Pubkey,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.fee_mint = fee_mint;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct VerifyLandParcel<'info> {
-    #[account(mut)]
-    pub land_parcel: Account<'info, LandParcel>,
-    pub authority: Signer<'info>,
-}
+    pub fn set_enforce_owner_token_account(
+        ctx: Context<UpdateTreasuryConfig>,
+        enforce_owner_token_account: bool,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.enforce_owner_token_account = enforce_owner_token_account;
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct UpdateLandOwnership<'info> {
-    #[account(mut)]
-    pub land_parcel: Account<'info, LandParcel>,
-    pub authority: Signer<'info>,
-}
+    pub fn set_rapid_transfer_limit(
+        ctx: Context<UpdateTreasuryConfig>,
+        rapid_transfer_limit: Option<RapidTransferLimit>,
+    ) -> Result<()> {
+        if let Some(limit) = &rapid_transfer_limit {
+            require!(
+                limit.max_transfers > 0
+                    && (limit.max_transfers as usize) < MAX_TRACKED_TRANSFERS
+                    && limit.window_seconds > 0
+                    && limit.review_seconds > 0,
+                ErrorCode::InvalidRapidTransferLimit
+            );
+        }
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.rapid_transfer_limit = rapid_transfer_limit;
+        
+        Ok(())
+    }
 
-#[account]
-pub struct Treasury {
-    pub authority: Pubkey,
-    pub treasury_bump: u8,
-    pub total_fees_collected: u64,
-    pub land_parcel_count: u64,
-    pub is_active: bool,
-}
+    pub fn set_auto_sweep_threshold(
+        ctx: Context<UpdateTreasuryConfig>,
+        auto_sweep_threshold: Option<u64>,
+    ) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.auto_sweep_threshold = auto_sweep_threshold;
+        
+        Ok(())
+    }
+
+    pub fn set_owner_mint_exemption(
+        ctx: Context<SetOwnerMintExemption>,
+        owner: Pubkey,
+        exempt: bool,
+    ) -> Result<()> {
+        let owner_mint_record = &mut ctx.accounts.owner_mint_record;
+        owner_mint_record.owner = owner;
+        owner_mint_record.exempt = exempt;
+        
+        emit!(OwnerMintExemptionUpdated { owner, exempt });
+        
+        Ok(())
+    }
+
+    pub fn set_district_treasury(
+        ctx: Context<SetDistrictTreasury>,
+        district: String,
+        token_account: Pubkey,
+    ) -> Result<()> {
+        require!(district.len() <= 32, ErrorCode::InvalidDistrictName);
+        
+        let district_treasury = &mut ctx.accounts.district_treasury;
+        district_treasury.district = location_seed(&district);
+        district_treasury.token_account = token_account;
+        
+        emit!(DistrictTreasuryUpdated {
+            district,
+            token_account,
+        });
+        
+        Ok(())
+    }
+
+    pub fn set_district_rules(
+        ctx: Context<SetDistrictRules>,
+        district: String,
+        min_area_sqm: u64,
+    ) -> Result<()> {
+        require!(district.len() <= 32, ErrorCode::InvalidLocationName);
+        
+        let district_rules = &mut ctx.accounts.district_rules;
+        district_rules.district = location_seed(&district);
+        district_rules.min_area_sqm = min_area_sqm;
+        
+        emit!(DistrictRulesUpdated {
+            district,
+            min_area_sqm,
+        });
+        
+        Ok(())
+    }
+
+    pub fn set_jurisdiction(
+        ctx: Context<SetJurisdiction>,
+        bounds: BoundingBox,
+    ) -> Result<()> {
+        require!(bounds.is_valid(), ErrorCode::InvalidCoordinates);
+        
+        ctx.accounts.jurisdiction.bounds = bounds;
+        
+        emit!(JurisdictionUpdated { bounds });
+        
+        Ok(())
+    }
+
+    pub fn set_village_config(
+        ctx: Context<SetVillageConfig>,
+        district: String,
+        taluka: String,
+        village: String,
+        requires_panchayat_approval: bool,
+        panchayat_signer: Pubkey,
+    ) -> Result<()> {
+        require!(
+            district.len() <= 32 && taluka.len() <= 32 && village.len() <= 32,
+            ErrorCode::InvalidLocationName
+        );
+        
+        let village_config = &mut ctx.accounts.village_config;
+        village_config.district = location_seed(&district);
+        village_config.taluka = location_seed(&taluka);
+        village_config.village = location_seed(&village);
+        village_config.requires_panchayat_approval = requires_panchayat_approval;
+        village_config.panchayat_signer = panchayat_signer;
+        
+        emit!(VillageConfigUpdated {
+            district,
+            taluka,
+            village,
+            requires_panchayat_approval,
+            panchayat_signer,
+        });
+        
+        Ok(())
+    }
+
+    pub fn accredit_firm(
+        ctx: Context<AccreditFirm>,
+        firm: Pubkey,
+        accredited_until: i64,
+    ) -> Result<()> {
+        let accredited_firm = &mut ctx.accounts.accredited_firm;
+        accredited_firm.firm = firm;
+        accredited_firm.accredited_until = accredited_until;
+        
+        emit!(FirmAccredited {
+            firm,
+            accredited_until,
+        });
+        
+        Ok(())
+    }
+
+    pub fn initialize_fee_config(
+        ctx: Context<InitializeFeeConfig>,
+        base_fee: u64,
+        per_sqm_fee: u64,
+    ) -> Result<()> {
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.base_fee = base_fee;
+        fee_config.per_sqm_fee = per_sqm_fee;
+        fee_config.class_multipliers_bps = [DEFAULT_CLASS_MULTIPLIER_BPS; LAND_USE_CLASSES];
+        
+        emit!(FeeConfigUpdated {
+            base_fee,
+            per_sqm_fee,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Sets the area-fee multiplier for each land-use class, indexed by the
+    /// `LandUse` code, in basis points (10000 = 1x)
+    pub fn set_class_fee_multipliers(
+        ctx: Context<UpdateFeeConfig>,
+        class_multipliers_bps: [u16; LAND_USE_CLASSES],
+    ) -> Result<()> {
+        ctx.accounts.fee_config.class_multipliers_bps = class_multipliers_bps;
+        
+        emit!(ClassFeeMultipliersUpdated {
+            class_multipliers_bps,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        Ok(())
+    }
+
+    pub fn update_fee_config(
+        ctx: Context<UpdateFeeConfig>,
+        base_fee: u64,
+        per_sqm_fee: u64,
+    ) -> Result<()> {
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.base_fee = base_fee;
+        fee_config.per_sqm_fee = per_sqm_fee;
+        
+        emit!(FeeConfigUpdated {
+            base_fee,
+            per_sqm_fee,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_land_parcel(
+        ctx: Context<RegisterLandParcel>,
+        ulpin_id: String,
+        area_sqm: u64,
+        district: String,
+        taluka: String,
+        village: String,
+        owner_pubkey: Pubkey,
+        document_hash: [u8; 32],
+        min_lat: i32,
+        min_lon: i32,
+        max_lat: i32,
+        max_lon: i32,
+        land_use: u8,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.treasury.is_paused(PAUSE_REGISTRATION),
+            ErrorCode::OperationPaused
+        );
+        
+        let input = LandParcelInput {
+            ulpin_id,
+            area_sqm,
+            district,
+            taluka,
+            village,
+            owner: owner_pubkey,
+            document_hash,
+            bbox: BoundingBox {
+                min_lat,
+                min_lon,
+                max_lat,
+                max_lon,
+            },
+            land_use: LandUse::try_from(land_use)?,
+        };
+        validate_registration(&input, &ctx.accounts.sanctions_list, &ctx.accounts.jurisdiction)?;
+        check_district_minimum(&ctx.accounts.district_rules, input.area_sqm)?;
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        
+        land_parcel.set_inner(LandParcel::registered(&input, Clock::get()?.unix_timestamp));
+        
+        treasury.land_parcel_count += 1;
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = land_parcel.district;
+        district_stats.registered_count += 1;
+        district_stats.emit_updated();
+        
+        emit!(LandParcelRegistered {
+            ulpin_id: input.ulpin_id,
+            owner: land_parcel.owner,
+            area_sqm: land_parcel.area_sqm,
+            registration_timestamp: land_parcel.registration_timestamp,
+            bbox: land_parcel.bbox,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Registered, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Registers several parcels of one district in one transaction. Parcel
+    /// PDAs are passed in `remaining_accounts` in the same order as `parcels`.
+    pub fn register_land_parcels_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterLandParcelsBatch<'info>>,
+        district: String,
+        parcels: Vec<LandParcelInput>,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.treasury.is_paused(PAUSE_REGISTRATION),
+            ErrorCode::OperationPaused
+        );
+        require!(
+            !parcels.is_empty() && parcels.len() <= MAX_REGISTRATION_BATCH,
+            ErrorCode::BatchTooLarge
+        );
+        require!(
+            parcels.len() == ctx.remaining_accounts.len(),
+            ErrorCode::InvalidBatchAccounts
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let authority = ctx.accounts.authority.key();
+        
+        // Parcel PDAs are passed in remaining_accounts, in input order, and created here
+        for (input, account_info) in parcels.iter().zip(ctx.remaining_accounts.iter()) {
+            validate_registration(input, &ctx.accounts.sanctions_list, &ctx.accounts.jurisdiction)?;
+            require!(
+                location_seed(&input.district) == location_seed(&district),
+                ErrorCode::BatchDistrictMismatch
+            );
+            check_district_minimum(&ctx.accounts.district_rules, input.area_sqm)?;
+            
+            let land_parcel = LandParcel::registered(input, now);
+            create_land_parcel_account(
+                account_info,
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                ctx.program_id,
+                &land_parcel,
+                &input.ulpin_id,
+            )?;
+            
+            emit_parcel_state_changed(&land_parcel, ParcelChangeType::Registered, authority)?;
+        }
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.land_parcel_count += parcels.len() as u64;
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = location_seed(&district);
+        district_stats.registered_count += parcels.len() as u64;
+        district_stats.emit_updated();
+        
+        emit!(LandParcelsBatchRegistered {
+            count: parcels.len() as u32,
+            first_ulpin: parcels[0].ulpin_id.clone(),
+            last_ulpin: parcels[parcels.len() - 1].ulpin_id.clone(),
+        });
+        
+        Ok(())
+    }
+
+    pub fn mint_land_nft(
+        ctx: Context<MintLandNFT>,
+        ulpin_id: String,
+        metadata_uri: String,
+        exemption_bps: u16,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        
+        check_mint_allowed(
+            land_parcel,
+            treasury,
+            &mut ctx.accounts.owner_mint_record,
+            &metadata_uri,
+            exemption_bps,
+        )?;
+        
+        if treasury.enforce_owner_token_account {
+            require_keys_eq!(
+                ctx.accounts.user_token_account.owner,
+                land_parcel.owner,
+                ErrorCode::TokenAccountOwnerMismatch
+            );
+        }
+        
+        // Fees go to the district's own token account when one is configured,
+        // and to a treasury-owned account otherwise
+        let district_treasury_info = ctx.accounts.district_treasury.to_account_info();
+        let mut district_treasury = if district_treasury_info.data_is_empty() {
+            None
+        } else {
+            Some(DistrictTreasury::try_deserialize(
+                &mut &district_treasury_info.try_borrow_data()?[..]
+            )?)
+        };
+        match &district_treasury {
+            Some(district_treasury) => require_keys_eq!(
+                ctx.accounts.treasury_token_account.key(),
+                district_treasury.token_account,
+                ErrorCode::DistrictTreasuryMismatch
+            ),
+            None => require_keys_eq!(
+                ctx.accounts.treasury_token_account.owner,
+                treasury.key(),
+                ErrorCode::InvalidTreasuryTokenAccount
+            ),
+        }
+        require_keys_eq!(
+            ctx.accounts.treasury_token_account.mint,
+            treasury.fee_mint,
+            ErrorCode::InvalidFeeMint
+        );
+        
+        let authority_cosigned = ctx.accounts.exemption_authority
+            .as_ref()
+            .map(|signer| signer.key())
+            == Some(treasury.authority);
+        let (total_fee, exemption_bps, discount, class_multiplier_bps) = mint_fee(
+            land_parcel.area_sqm,
+            land_parcel.land_use,
+            &ctx.accounts.fee_config,
+            exemption_bps,
+            authority_cosigned,
+        )?;
+        
+        let balance_before = treasury.current_balance;
+        
+        // Transfer fees to treasury
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, total_fee)?;
+        
+        land_parcel.nft_minted = true;
+        land_parcel.mint_timestamp = Some(Clock::get()?.unix_timestamp);
+        land_parcel.metadata_uri = metadata_uri.clone();
+        land_parcel.nft_mint = Some(ctx.accounts.nft_mint.key());
+        treasury.total_fees_collected += total_fee;
+        treasury.current_balance += total_fee;
+        ctx.accounts.land_use_revenue.token_fees[land_parcel.land_use as usize] += total_fee;
+        if let Some(district_treasury) = district_treasury.as_mut() {
+            district_treasury.total_collected += total_fee;
+            district_treasury.try_serialize(&mut &mut district_treasury_info.try_borrow_mut_data()?[..])?;
+        }
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = land_parcel.district;
+        district_stats.minted_count += 1;
+        district_stats.emit_updated();
+        
+        // Signal the off-chain sweep job the first time the balance crosses the
+        // threshold; tokens sent to fee accounts directly are not counted
+        if let Some(threshold) = treasury.auto_sweep_threshold {
+            let available_balance = treasury.current_balance;
+            if balance_before < threshold && available_balance >= threshold {
+                emit!(TreasurySweepRecommended {
+                    treasury_token_account: ctx.accounts.treasury_token_account.key(),
+                    available_balance,
+                    threshold,
+                });
+            }
+        }
+        
+        emit!(NFTMinted {
+            ulpin_id: ulpin_id.clone(),
+            owner: land_parcel.owner,
+            nft_mint: ctx.accounts.nft_mint.key(),
+            metadata_uri,
+            fee_paid: total_fee,
+            exemption_bps,
+            discount,
+            class_multiplier_bps,
+            land_use: land_parcel.land_use,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::NftMinted, ctx.accounts.user.key())?;
+        
+        Ok(())
+    }
+
+    /// Same as `mint_land_nft`, but the fee is paid in lamports into the
+    /// treasury PDA for users who only hold SOL
+    pub fn mint_land_nft_sol(
+        ctx: Context<MintLandNFTSol>,
+        ulpin_id: String,
+        metadata_uri: String,
+        exemption_bps: u16,
+    ) -> Result<()> {
+        check_mint_allowed(
+            &ctx.accounts.land_parcel,
+            &ctx.accounts.treasury,
+            &mut ctx.accounts.owner_mint_record,
+            &metadata_uri,
+            exemption_bps,
+        )?;
+        
+        let authority_cosigned = ctx.accounts.exemption_authority
+            .as_ref()
+            .map(|signer| signer.key())
+            == Some(ctx.accounts.treasury.authority);
+        let (total_fee, exemption_bps, discount, class_multiplier_bps) = mint_fee(
+            ctx.accounts.land_parcel.area_sqm,
+            ctx.accounts.land_parcel.land_use,
+            &ctx.accounts.fee_config,
+            exemption_bps,
+            authority_cosigned,
+        )?;
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            total_fee,
+        )?;
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.nft_minted = true;
+        land_parcel.mint_timestamp = Some(Clock::get()?.unix_timestamp);
+        land_parcel.metadata_uri = metadata_uri.clone();
+        land_parcel.nft_mint = Some(ctx.accounts.nft_mint.key());
+        ctx.accounts.treasury.sol_fees_collected += total_fee;
+        ctx.accounts.land_use_revenue.sol_fees[land_parcel.land_use as usize] += total_fee;
+        
+        // Lamports all land in the treasury PDA, but are still attributed to
+        // the parcel's district when it has a treasury of its own
+        let district_treasury_info = ctx.accounts.district_treasury.to_account_info();
+        if !district_treasury_info.data_is_empty() {
+            let mut district_treasury = DistrictTreasury::try_deserialize(
+                &mut &district_treasury_info.try_borrow_data()?[..]
+            )?;
+            district_treasury.sol_collected += total_fee;
+            district_treasury.try_serialize(&mut &mut district_treasury_info.try_borrow_mut_data()?[..])?;
+        }
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = land_parcel.district;
+        district_stats.minted_count += 1;
+        district_stats.emit_updated();
+        
+        emit!(NFTMinted {
+            ulpin_id: ulpin_id.clone(),
+            owner: land_parcel.owner,
+            nft_mint: ctx.accounts.nft_mint.key(),
+            metadata_uri,
+            fee_paid: total_fee,
+            exemption_bps,
+            discount,
+            class_multiplier_bps,
+            land_use: land_parcel.land_use,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::NftMinted, ctx.accounts.user.key())?;
+        
+        Ok(())
+    }
+
+    /// Clears `nft_minted` once the parcel's recorded NFT has been burned,
+    /// so `reissue_land_nft` can replace it. `nft_mint` is kept as the
+    /// record that the parcel was minted before.
+    pub fn record_nft_burn(ctx: Context<RecordNftBurn>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(ctx.accounts.nft_mint.supply == 0, ErrorCode::NFTNotBurned);
+        
+        land_parcel.nft_minted = false;
+        
+        emit!(LandNFTBurnRecorded {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::NftBurned, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Points a parcel whose NFT was lost at a fresh NFT already held by the
+    /// recorded owner. No fee is charged and mint limits are not counted
+    /// again, since the parcel was paid for when first minted.
+    pub fn reissue_land_nft(
+        ctx: Context<ReissueLandNFT>,
+        ulpin_id: String,
+        new_mint: Pubkey,
+    ) -> Result<()> {
+        require_keys_eq!(ctx.accounts.new_mint.key(), new_mint, ErrorCode::MintMismatch);
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require!(!land_parcel.nft_minted, ErrorCode::NFTAlreadyMinted);
+        let old_mint = land_parcel.nft_mint.ok_or(ErrorCode::NFTNeverMinted)?;
+        
+        land_parcel.nft_minted = true;
+        land_parcel.nft_mint = Some(new_mint);
+        
+        emit!(LandNFTReissued {
+            ulpin_id,
+            owner: land_parcel.owner,
+            old_mint,
+            new_mint,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::NftReissued, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Moves a parcel's NFT from the classic token program to Token-2022:
+    /// burns the owner's old token and mints the new one to the same owner.
+    /// The new mint must be empty, owned by the owner and share the old
+    /// mint's freeze authority so court freezes keep working.
+    pub fn migrate_nft_standard(
+        ctx: Context<MigrateNftStandard>,
+        ulpin_id: String,
+        new_mint: Pubkey,
+    ) -> Result<()> {
+        require_keys_eq!(ctx.accounts.new_mint.key(), new_mint, ErrorCode::MintMismatch);
+        
+        let land_parcel = &ctx.accounts.land_parcel;
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(land_parcel.freeze_start_timestamp.is_none(), ErrorCode::AlreadyFrozen);
+        require!(
+            !land_parcel.is_quarantined(Clock::get()?.unix_timestamp),
+            ErrorCode::ParcelQuarantined
+        );
+        
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.old_mint.to_account_info(),
+                    from: ctx.accounts.owner_old_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+        
+        token_2022::mint_to(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.new_mint.to_account_info(),
+                    to: ctx.accounts.owner_new_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+        
+        // Fix the supply at one so the new token stays non-fungible
+        token_2022::set_authority(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.owner.to_account_info(),
+                    account_or_mint: ctx.accounts.new_mint.to_account_info(),
+                },
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let old_mint = ctx.accounts.old_mint.key();
+        land_parcel.nft_mint = Some(new_mint);
+        
+        emit!(NFTStandardMigrated {
+            ulpin_id,
+            owner: land_parcel.owner,
+            old_mint,
+            new_mint,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::NftStandardMigrated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Retry-safe variant of `verify_land_parcel` for relayers that cannot
+    /// tell whether an earlier submission landed: an already verified parcel
+    /// is a no-op with no events, anything else goes through the strict path.
+    pub fn ensure_verified(
+        ctx: Context<VerifyLandParcel>,
+        ulpin_id: String,
+    ) -> Result<()> {
+        if ctx.accounts.land_parcel.is_verified {
+            return Ok(());
+        }
+        verify_land_parcel(ctx, ulpin_id)
+    }
+
+    pub fn verify_land_parcel(
+        ctx: Context<VerifyLandParcel>,
+        ulpin_id: String,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.treasury.is_paused(PAUSE_VERIFICATION),
+            ErrorCode::OperationPaused
+        );
+        
+        let verifier = ctx.accounts.authority.key();
+        let required_approvals = ctx.accounts.treasury.required_approvals;
+        let registered = matches!(
+            ctx.accounts.verifier_registry.as_ref(),
+            Some(registry) if registry.is_verifier(&verifier)
+        );
+        if required_approvals > 0 {
+            require!(registered, ErrorCode::UnauthorizedVerifier);
+        } else {
+            // The accredited firm account is seeded by the signer, so its
+            // presence means the signer is an accredited firm
+            require!(
+                registered
+                    || verifier == ctx.accounts.treasury.authority
+                    || ctx.accounts.accredited_firm.is_some(),
+                ErrorCode::UnauthorizedVerifier
+            );
+        }
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!(!land_parcel.is_verified, ErrorCode::AlreadyVerified);
+        require!(land_parcel.document_hash != [0u8; 32], ErrorCode::MissingDocumentHash);
+        
+        // The owner co-signature only counts if it comes from the current owner
+        let owner_ack = ctx.accounts.owner
+            .as_ref()
+            .map(|owner| owner.key())
+            .filter(|owner| *owner == land_parcel.owner);
+        
+        let verification_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Accredited surveying firms may verify on the state's behalf while
+        // their accreditation is current
+        let accredited_firm = ctx.accounts.accredited_firm.is_some();
+        if let Some(firm) = ctx.accounts.accredited_firm.as_ref() {
+            require!(
+                verification_timestamp <= firm.accredited_until,
+                ErrorCode::AccreditationExpired
+            );
+        }
+        
+        let verification_state = &mut ctx.accounts.verification_state;
+        verification_state.land_parcel = land_parcel.key();
+        
+        // Stale votes no longer count and may be re-cast
+        let vote_validity_seconds = ctx.accounts.treasury.vote_validity_seconds;
+        if vote_validity_seconds > 0 {
+            verification_state.approvals.retain(|vote| {
+                verification_timestamp - vote.approved_at < vote_validity_seconds
+            });
+        }
+        // Votes from verifiers since removed from the registry no longer count
+        if let Some(registry) = ctx.accounts.verifier_registry.as_ref() {
+            verification_state.approvals.retain(|vote| registry.is_verifier(&vote.verifier));
+        }
+        require!(
+            !verification_state.approvals.iter().any(|vote| vote.verifier == verifier),
+            ErrorCode::AlreadyApproved
+        );
+        verification_state.approvals.push(VerificationVote {
+            verifier,
+            approved_at: verification_timestamp,
+        });
+        let approvals_so_far = verification_state.approvals.len() as u8;
+        
+        emit!(VerificationApproved {
+            ulpin_id: ulpin_id.clone(),
+            verifier,
+            approvals_so_far,
+        });
+        
+        // Without a configured quorum a single approval verifies, as before
+        if approvals_so_far < required_approvals.max(1) {
+            return Ok(());
+        }
+        if ctx.accounts.treasury.owner_ack_required {
+            require!(owner_ack.is_some(), ErrorCode::OwnerAckRequired);
+        }
+        // Start a fresh round in case the parcel has to be re-verified later
+        verification_state.approvals.clear();
+        
+        land_parcel.is_verified = true;
+        land_parcel.verified_at = Some(verification_timestamp);
+        land_parcel.verified_by = Some(ctx.accounts.authority.key());
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = land_parcel.district;
+        district_stats.verified_count += 1;
+        district_stats.emit_updated();
+        
+        emit!(LandParcelVerified {
+            ulpin_id: ulpin_id.clone(),
+            verifier: ctx.accounts.authority.key(),
+            owner_ack,
+            accredited_firm,
+            verification_timestamp,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Verified, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Verifies several parcels of one district in one transaction. Parcel
+    /// accounts are passed in `remaining_accounts` in the same order as
+    /// `ulpin_ids`, one per id. Parcels that are already verified are skipped and listed in
+    /// `ParcelsBatchVerified` rather than failing the batch. Only available
+    /// in single-signer mode without owner acknowledgement, since neither a
+    /// quorum nor owner co-signatures can be collected per parcel here.
+    pub fn verify_parcels_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyParcelsBatch<'info>>,
+        district: String,
+        ulpin_ids: Vec<String>,
+    ) -> Result<()> {
+        let treasury = &ctx.accounts.treasury;
+        require!(!treasury.is_paused(PAUSE_VERIFICATION), ErrorCode::OperationPaused);
+        require!(
+            !ulpin_ids.is_empty() && ulpin_ids.len() <= MAX_VERIFICATION_BATCH,
+            ErrorCode::BatchTooLarge
+        );
+        require!(
+            ulpin_ids.len() == ctx.remaining_accounts.len(),
+            ErrorCode::InvalidBatchAccounts
+        );
+        require!(
+            treasury.required_approvals == 0 && !treasury.owner_ack_required,
+            ErrorCode::BatchVerificationUnavailable
+        );
+        
+        let verifier = ctx.accounts.authority.key();
+        let registered = matches!(
+            ctx.accounts.verifier_registry.as_ref(),
+            Some(registry) if registry.is_verifier(&verifier)
+        );
+        require!(
+            registered || verifier == treasury.authority,
+            ErrorCode::UnauthorizedVerifier
+        );
+        
+        let verification_timestamp = Clock::get()?.unix_timestamp;
+        let mut verified_count: u32 = 0;
+        let mut skipped = Vec::new();
+        
+        for (ulpin_id, account_info) in ulpin_ids.iter().zip(ctx.remaining_accounts.iter()) {
+            require!(ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
+            let mut land_parcel = Account::<LandParcel>::try_from(account_info)?;
+            
+            let mut ulpin_bytes = [0u8; 64];
+            ulpin_bytes[..ulpin_id.len()].copy_from_slice(ulpin_id.as_bytes());
+            require!(land_parcel.ulpin_id == ulpin_bytes, ErrorCode::InvalidBatchAccounts);
+            require!(
+                land_parcel.district == location_seed(&district),
+                ErrorCode::BatchDistrictMismatch
+            );
+            
+            if land_parcel.is_verified {
+                skipped.push(ulpin_id.clone());
+                continue;
+            }
+            require!(land_parcel.document_hash != [0u8; 32], ErrorCode::MissingDocumentHash);
+            
+            land_parcel.is_verified = true;
+            land_parcel.verified_at = Some(verification_timestamp);
+            land_parcel.verified_by = Some(verifier);
+            land_parcel.exit(ctx.program_id)?;
+            verified_count += 1;
+            
+            emit!(LandParcelVerified {
+                ulpin_id: ulpin_id.clone(),
+                verifier,
+                owner_ack: None,
+                accredited_firm: false,
+                verification_timestamp,
+            });
+            
+            emit_parcel_state_changed(&land_parcel, ParcelChangeType::Verified, verifier)?;
+        }
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = location_seed(&district);
+        district_stats.verified_count += u64::from(verified_count);
+        district_stats.emit_updated();
+        
+        emit!(ParcelsBatchVerified {
+            verifier,
+            verified_count,
+            skipped_count: skipped.len() as u32,
+            skipped,
+        });
+        
+        Ok(())
+    }
+
+    /// Brings a parcel record up to `CURRENT_VERSION`, one layout step at a
+    /// time. Run `realloc_land_parcel` first so the appended fields exist.
+    pub fn migrate_land_parcel(ctx: Context<AdminUpdateLandParcel>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let from_version = land_parcel.version;
+        require!(from_version < CURRENT_VERSION, ErrorCode::NothingToMigrate);
+        
+        // Appended fields are read from whatever trailed the old record, which
+        // is not guaranteed to be zero, so each step resets what it added
+        if from_version < 1 {
+            // Everything after the original fields was appended before the
+            // version existed; give it the defaults a fresh registration gets
+            land_parcel.mint_timestamp = None;
+            land_parcel.protected = false;
+            land_parcel.protection_authority = Pubkey::default();
+            land_parcel.transfer_window = None;
+            land_parcel.verified_at = None;
+            land_parcel.verified_by = None;
+            land_parcel.occupancy_status = OccupancyStatus::OwnerOccupied;
+            land_parcel.possessor = None;
+            land_parcel.quarantine_until = 0;
+            land_parcel.case_reference = [0u8; 32];
+            land_parcel.under_arbitration = false;
+            land_parcel.subsurface_owner = land_parcel.owner;
+            land_parcel.easement_count = 0;
+            land_parcel.transfer_blocking_easements = 0;
+            land_parcel.recent_transfers = [0; MAX_TRACKED_TRANSFERS];
+            land_parcel.ownership_type = OwnershipType::Individual;
+            land_parcel.freeze_reason = None;
+            land_parcel.subdivided = false;
+            land_parcel.parent_ulpin = [0u8; 64];
+            land_parcel.merged = false;
+            land_parcel.document_hash = [0u8; 32];
+            land_parcel.metadata_uri = String::new();
+            land_parcel.nft_mint = None;
+            land_parcel.ownership_history_count = 0;
+            land_parcel.bbox = BoundingBox::default();
+            land_parcel.under_dispute = false;
+            land_parcel.dispute_claimant = None;
+            land_parcel.land_use = LandUse::Agricultural;
+            land_parcel.transfer_pending = false;
+            land_parcel.pending_owner = None;
+            land_parcel.pending_owner_effective_at = 0;
+        }
+        if from_version < 2 {
+            // v2 added lien tracking; a parcel from before it has no liens
+            land_parcel.active_liens = 0;
+        }
+        if from_version < 3 {
+            // v3 added the metadata update rate limit
+            land_parcel.last_metadata_update = 0;
+        }
+        if from_version < 4 {
+            // v4 numbers liens by priority; liens already in place keep the
+            // numbers below the sequence and new ones rank after them
+            land_parcel.lien_sequence = land_parcel.active_liens as u32;
+        }
+        if from_version < 5 {
+            // v5 records who staged a pending change; one staged before it
+            // was staged against the current owner
+            land_parcel.pending_owner_staged_from = land_parcel.owner;
+        }
+        
+        land_parcel.version = CURRENT_VERSION;
+        
+        emit!(LandParcelMigrated {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            from_version,
+            to_version: CURRENT_VERSION,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Migrated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Grows a parcel account created under an older, shorter layout to
+    /// `LandParcel::SPACE`. Taken as raw account info because the stored
+    /// bytes may not deserialize into the current struct yet.
+    pub fn realloc_land_parcel(ctx: Context<ReallocLandParcel>) -> Result<()> {
+        let land_parcel = ctx.accounts.land_parcel.to_account_info();
+        let old_len = land_parcel.data_len();
+        let new_len = LandParcel::SPACE;
+        require!(old_len <= new_len, ErrorCode::AccountShrinkNotAllowed);
+        require!(old_len < new_len, ErrorCode::NothingToMigrate);
+        
+        let ulpin_id = {
+            let data = land_parcel.try_borrow_data()?;
+            require!(
+                old_len >= 8 + 64 && data[..8] == LandParcel::DISCRIMINATOR,
+                ErrorCode::InvalidLandParcelAccount
+            );
+            String::from_utf8_lossy(&data[8..8 + 64]).trim_matches('\0').to_string()
+        };
+        
+        let required = Rent::get()?.minimum_balance(new_len);
+        let shortfall = required.saturating_sub(land_parcel.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: land_parcel.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        
+        land_parcel.realloc(new_len, true)?;
+        
+        emit!(LandParcelRealloc {
+            ulpin_id,
+            old_len: old_len as u32,
+            new_len: new_len as u32,
+        });
+        
+        Ok(())
+    }
+
+    pub fn reclassify_parcel(
+        ctx: Context<AdminUpdateLandParcel>,
+        new_use: u8,
+    ) -> Result<()> {
+        let new_use = LandUse::try_from(new_use)?;
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let previous_use = land_parcel.land_use;
+        land_parcel.land_use = new_use;
+        
+        emit!(ParcelReclassified {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            previous_use,
+            new_use,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Reclassified, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_verification_quorum(
+        ctx: Context<SetVerificationQuorum>,
+        verifiers: Vec<Pubkey>,
+        required_approvals: u8,
+    ) -> Result<()> {
+        let mut verifiers = verifiers;
+        verifiers.sort();
+        verifiers.dedup();
+        require!(verifiers.len() <= MAX_VERIFIERS, ErrorCode::VerifierRegistryFull);
+        require!(
+            usize::from(required_approvals) <= verifiers.len(),
+            ErrorCode::InvalidQuorum
+        );
+        
+        ctx.accounts.verifier_registry.verifiers = verifiers;
+        ctx.accounts.treasury.required_approvals = required_approvals;
+        
+        emit!(VerificationQuorumUpdated {
+            verifiers: ctx.accounts.verifier_registry.verifiers.clone(),
+            required_approvals,
+        });
+        
+        Ok(())
+    }
+
+    pub fn add_verifier(
+        ctx: Context<UpdateVerifierRegistry>,
+        verifier: Pubkey,
+    ) -> Result<()> {
+        let verifier_registry = &mut ctx.accounts.verifier_registry;
+        
+        if !verifier_registry.is_verifier(&verifier) {
+            require!(
+                verifier_registry.verifiers.len() < MAX_VERIFIERS,
+                ErrorCode::VerifierRegistryFull
+            );
+            verifier_registry.verifiers.push(verifier);
+        }
+        
+        emit!(VerifierAdded { verifier });
+        
+        Ok(())
+    }
+
+    pub fn remove_verifier(
+        ctx: Context<UpdateVerifierRegistry>,
+        verifier: Pubkey,
+    ) -> Result<()> {
+        let verifier_registry = &mut ctx.accounts.verifier_registry;
+        
+        let index = verifier_registry.verifiers
+            .iter()
+            .position(|registered| *registered == verifier)
+            .ok_or(ErrorCode::VerifierNotFound)?;
+        verifier_registry.verifiers.swap_remove(index);
+        
+        // Removing an officer must not leave the quorum unreachable
+        require!(
+            usize::from(ctx.accounts.treasury.required_approvals) <= verifier_registry.verifiers.len(),
+            ErrorCode::InvalidQuorum
+        );
+        
+        emit!(VerifierRemoved { verifier });
+        
+        Ok(())
+    }
+
+    pub fn update_land_ownership(
+        ctx: Context<UpdateLandOwnership>,
+        ulpin_id: String,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        let signer = ctx.accounts.authority.key();
+        require!(
+            signer == land_parcel.owner || signer == ctx.accounts.treasury.authority,
+            ErrorCode::UnauthorizedOwnershipTransfer
+        );
+        require!(
+            !land_parcel.transfer_pending && land_parcel.pending_owner.is_none(),
+            ErrorCode::TransferAlreadyPending
+        );
+        check_transfer_allowed(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &ctx.accounts.sanctions_list,
+            &ctx.accounts.village_config,
+            new_owner,
+            ctx.accounts.protection_authority.as_ref().map(|signer| signer.key()),
+            ctx.accounts.panchayat.as_ref().map(|signer| signer.key()),
+            ctx.accounts.karta.as_ref().map(|signer| signer.key()),
+        )?;
+        
+        // With a delay configured the change is only staged, so the owner can
+        // contest it before anyone calls finalize_ownership
+        let delay = ctx.accounts.treasury.transfer_delay_seconds;
+        if delay > 0 {
+            let effective_at = Clock::get()?.unix_timestamp + delay;
+            land_parcel.pending_owner = Some(new_owner);
+            land_parcel.pending_owner_effective_at = effective_at;
+            land_parcel.pending_owner_staged_from = land_parcel.owner;
+            
+            emit!(OwnershipChangeStaged {
+                ulpin_id,
+                current_owner: land_parcel.owner,
+                pending_owner: new_owner,
+                effective_at,
+            });
+            
+            emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipChangeStaged, ctx.accounts.authority.key())?;
+            
+            return Ok(());
+        }
+        
+        apply_ownership_change(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &mut ctx.accounts.ownership_history,
+            ulpin_id,
+            new_owner,
+        )?;
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipTransferred, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Applies a staged ownership change once its challenge window has
+    /// passed. Anyone may crank this; the payer only funds a new history page.
+    /// The transfer checks are re-run, so approvals the parcel needs (such
+    /// as a protection authority or panchayat) must co-sign here as well.
+    pub fn finalize_ownership(ctx: Context<FinalizeOwnership>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let now = Clock::get()?.unix_timestamp;
+        
+        let new_owner = land_parcel.pending_owner.ok_or(ErrorCode::NoPendingOwnershipChange)?;
+        require!(
+            now >= land_parcel.pending_owner_effective_at,
+            ErrorCode::TransferDelayNotElapsed
+        );
+        require_keys_eq!(
+            land_parcel.owner,
+            land_parcel.pending_owner_staged_from,
+            ErrorCode::StagedOwnerChanged
+        );
+        // Holds and restrictions placed during the challenge window still apply
+        check_transfer_allowed(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &ctx.accounts.sanctions_list,
+            &ctx.accounts.village_config,
+            new_owner,
+            ctx.accounts.protection_authority.as_ref().map(|signer| signer.key()),
+            ctx.accounts.panchayat.as_ref().map(|signer| signer.key()),
+            ctx.accounts.karta.as_ref().map(|signer| signer.key()),
+        )?;
+        
+        let ulpin_id = String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string();
+        apply_ownership_change(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &mut ctx.accounts.ownership_history,
+            ulpin_id,
+            new_owner,
+        )?;
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipTransferred, ctx.accounts.payer.key())?;
+        
+        Ok(())
+    }
+
+    /// Lets the current owner cancel a staged ownership change during its
+    /// challenge window
+    pub fn contest_ownership_change(ctx: Context<ContestOwnershipChange>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require_keys_eq!(ctx.accounts.owner.key(), land_parcel.owner, ErrorCode::Unauthorized);
+        
+        let pending_owner = land_parcel.pending_owner.take().ok_or(ErrorCode::NoPendingOwnershipChange)?;
+        land_parcel.pending_owner_effective_at = 0;
+        
+        emit!(OwnershipChangeContested {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            owner: land_parcel.owner,
+            pending_owner,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipChangeContested, land_parcel.owner)?;
+        
+        Ok(())
+    }
+
+    /// Opens an escrowed sale: the buyer co-signs and deposits `price`
+    /// lamports into the `PendingTransfer` PDA, which holds them until the
+    /// buyer accepts or either party cancels. With a transfer delay
+    /// configured the proposal doubles as the staged change, and the buyer
+    /// can only accept once the challenge window has passed.
+    pub fn propose_transfer(
+        ctx: Context<ProposeTransfer>,
+        buyer: Pubkey,
+        price: u64,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(
+            !land_parcel.transfer_pending && land_parcel.pending_owner.is_none(),
+            ErrorCode::TransferAlreadyPending
+        );
+        require_keys_eq!(ctx.accounts.seller.key(), land_parcel.owner, ErrorCode::UnauthorizedOwnershipTransfer);
+        require_keys_eq!(ctx.accounts.buyer.key(), buyer, ErrorCode::InvalidBuyer);
+        require_keys_neq!(buyer, land_parcel.owner, ErrorCode::InvalidBuyer);
+        check_transfer_allowed(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &ctx.accounts.sanctions_list,
+            &ctx.accounts.village_config,
+            buyer,
+            ctx.accounts.protection_authority.as_ref().map(|signer| signer.key()),
+            ctx.accounts.panchayat.as_ref().map(|signer| signer.key()),
+            ctx.accounts.karta.as_ref().map(|signer| signer.key()),
+        )?;
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.pending_transfer.to_account_info(),
+                },
+            ),
+            price,
+        )?;
+        
+        let pending_transfer = &mut ctx.accounts.pending_transfer;
+        pending_transfer.land_parcel = land_parcel.key();
+        pending_transfer.seller = land_parcel.owner;
+        pending_transfer.buyer = buyer;
+        pending_transfer.price = price;
+        pending_transfer.proposed_at = now;
+        land_parcel.transfer_pending = true;
+        
+        emit!(TransferProposed {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            seller: land_parcel.owner,
+            buyer,
+            price,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::TransferProposed, ctx.accounts.seller.key())?;
+        
+        Ok(())
+    }
+
+    /// Completes an escrowed sale: pays the seller from escrow and hands the
+    /// parcel to the buyer. The escrow's rent goes back to the buyer.
+    pub fn accept_transfer(ctx: Context<AcceptTransfer>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let buyer = ctx.accounts.buyer.key();
+        
+        // An arbitration ruling may have moved the parcel after the proposal
+        require_keys_eq!(land_parcel.owner, ctx.accounts.pending_transfer.seller, ErrorCode::Unauthorized);
+        // Holds, approvals and sanctions may have changed since the proposal
+        check_transfer_allowed(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &ctx.accounts.sanctions_list,
+            &ctx.accounts.village_config,
+            buyer,
+            ctx.accounts.protection_authority.as_ref().map(|signer| signer.key()),
+            ctx.accounts.panchayat.as_ref().map(|signer| signer.key()),
+            ctx.accounts.karta.as_ref().map(|signer| signer.key()),
+        )?;
+        // The owner can cancel the sale during the challenge window
+        require!(
+            Clock::get()?.unix_timestamp
+                >= ctx.accounts.pending_transfer.proposed_at + ctx.accounts.treasury.transfer_delay_seconds,
+            ErrorCode::TransferDelayNotElapsed
+        );
+        
+        let price = ctx.accounts.pending_transfer.price;
+        **ctx.accounts.pending_transfer.to_account_info().try_borrow_mut_lamports()? -= price;
+        **ctx.accounts.seller.try_borrow_mut_lamports()? += price;
+        
+        let ulpin_id = String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string();
+        let seller = land_parcel.owner;
+        land_parcel.transfer_pending = false;
+        apply_ownership_change(
+            land_parcel,
+            &ctx.accounts.treasury,
+            &mut ctx.accounts.ownership_history,
+            ulpin_id.clone(),
+            buyer,
+        )?;
+        
+        emit!(TransferAccepted {
+            ulpin_id,
+            seller,
+            buyer,
+            price,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipTransferred, buyer)?;
+        
+        Ok(())
+    }
+
+    /// Records a lender's charge against the parcel. Both the owner and the
+    /// lender sign; the lender pays for the `Lien` PDA. Each lender holds at
+    /// most one lien per parcel, ranked by registration order. The parcel
+    /// cannot change hands or be minted until every lien is released.
+    pub fn register_lien(
+        ctx: Context<RegisterLien>,
+        lender: Pubkey,
+        amount: u64,
+        maturity: i64,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require_keys_eq!(ctx.accounts.owner.key(), land_parcel.owner, ErrorCode::Unauthorized);
+        require_keys_eq!(ctx.accounts.lender.key(), lender, ErrorCode::InvalidLender);
+        require_keys_neq!(lender, land_parcel.owner, ErrorCode::InvalidLender);
+        require!(amount > 0, ErrorCode::InvalidLienAmount);
+        require!(maturity > now, ErrorCode::InvalidLienMaturity);
+        require!(land_parcel.active_liens < MAX_ACTIVE_LIENS, ErrorCode::TooManyLiens);
+        
+        land_parcel.lien_sequence += 1;
+        let lien = &mut ctx.accounts.lien;
+        lien.land_parcel = land_parcel.key();
+        lien.lender = lender;
+        lien.amount = amount;
+        lien.maturity = maturity;
+        lien.registered_at = now;
+        lien.priority = land_parcel.lien_sequence;
+        land_parcel.active_liens += 1;
+        
+        emit!(LienRegistered {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            lender,
+            amount,
+            maturity,
+            priority: lien.priority,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::LienRegistered, ctx.accounts.owner.key())?;
+        
+        Ok(())
+    }
+
+    /// Discharges a lien. Only the lender can release it; the PDA's rent
+    /// goes back to them.
+    pub fn release_lien(ctx: Context<ReleaseLien>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.active_liens = land_parcel.active_liens.saturating_sub(1);
+        
+        emit!(LienReleased {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            lender: ctx.accounts.lender.key(),
+            amount: ctx.accounts.lien.amount,
+            priority: ctx.accounts.lien.priority,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::LienReleased, ctx.accounts.lender.key())?;
+        
+        Ok(())
+    }
+
+    /// Abandons an escrowed sale; either party may cancel and the buyer gets
+    /// the full deposit back
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>) -> Result<()> {
+        let pending_transfer = &ctx.accounts.pending_transfer;
+        let cancelled_by = ctx.accounts.authority.key();
+        require!(
+            cancelled_by == pending_transfer.seller || cancelled_by == pending_transfer.buyer,
+            ErrorCode::Unauthorized
+        );
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.transfer_pending = false;
+        
+        emit!(TransferCancelled {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            seller: pending_transfer.seller,
+            buyer: pending_transfer.buyer,
+            price: pending_transfer.price,
+            cancelled_by,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::TransferCancelled, cancelled_by)?;
+        
+        Ok(())
+    }
+
+    pub fn transfer_subsurface_rights(
+        ctx: Context<TransferSubsurfaceRights>,
+        ulpin_id: String,
+        new_subsurface_owner: Pubkey,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+        
+        // Subsurface rights are encumbered by the same holds as the surface
+        let now = Clock::get()?.unix_timestamp;
+        require!(!land_parcel.is_frozen(now), ErrorCode::ParcelFrozen);
+        require!(!land_parcel.is_quarantined(now), ErrorCode::ParcelQuarantined);
+        require!(land_parcel.active_liens == 0, ErrorCode::ActiveLienExists);
+        require!(!land_parcel.under_dispute, ErrorCode::ParcelUnderDispute);
+        require!(!land_parcel.under_arbitration, ErrorCode::ParcelUnderArbitration);
+        require!(
+            !ctx.accounts.sanctions_list.is_sanctioned(&new_subsurface_owner),
+            ErrorCode::SanctionedParty
+        );
+        
+        let previous_subsurface_owner = land_parcel.subsurface_owner;
+        land_parcel.subsurface_owner = new_subsurface_owner;
+        
+        emit!(SubsurfaceRightsTransferred {
+            ulpin_id,
+            surface_owner: land_parcel.owner,
+            previous_subsurface_owner,
+            new_subsurface_owner,
+            transfer_timestamp: now,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::SubsurfaceRightsTransferred, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_parcel_protection(
+        ctx: Context<SetParcelProtection>,
+        ulpin_id: String,
+        protected: bool,
+        protection_authority: Pubkey,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        land_parcel.protected = protected;
+        land_parcel.protection_authority = if protected {
+            protection_authority
+        } else {
+            Pubkey::default()
+        };
+        
+        emit!(ParcelProtectionUpdated {
+            ulpin_id,
+            protected,
+            protection_authority: land_parcel.protection_authority,
+            updated_by: ctx.accounts.heritage_authority.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::ProtectionUpdated, ctx.accounts.heritage_authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Records a new metadata URI on the parcel. Updates are spaced by the
+    /// treasury's `min_metadata_update_interval`; `force` lets the authority
+    /// push a correction through inside the interval.
+    ///
+    /// Deferred: the Metaplex `update_metadata_accounts_v2` CPI. The program
+    /// does not depend on mpl-token-metadata yet, so only the program's own
+    /// record changes and indexers must read the URI from `LandParcel` or
+    /// `MetadataURIUpdated` until that dependency is added.
+    pub fn update_metadata_uri(
+        ctx: Context<AdminUpdateLandParcel>,
+        new_uri: String,
+        force: bool,
+    ) -> Result<()> {
+        require!(new_uri.len() <= MAX_METADATA_URI_LEN, ErrorCode::InvalidMetadataURI);
+        
+        let min_interval = ctx.accounts.treasury.min_metadata_update_interval;
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(!land_parcel.is_frozen(now), ErrorCode::ParcelFrozen);
+        require!(
+            force
+                || land_parcel.last_metadata_update == 0
+                || now - land_parcel.last_metadata_update >= min_interval,
+            ErrorCode::MetadataUpdateTooFrequent
+        );
+        
+        let old_uri = std::mem::replace(&mut land_parcel.metadata_uri, new_uri.clone());
+        land_parcel.last_metadata_update = now;
+        
+        emit!(MetadataURIUpdated {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            old_uri,
+            new_uri,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::MetadataUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn update_document_hash(
+        ctx: Context<AdminUpdateLandParcel>,
+        new_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(new_hash != [0u8; 32], ErrorCode::MissingDocumentHash);
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let old_hash = land_parcel.document_hash;
+        land_parcel.document_hash = new_hash;
+        
+        emit!(DocumentHashUpdated {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            old_hash,
+            new_hash,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::DocumentHashUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_ownership_type(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+        ownership_type: OwnershipType,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.ownership_type = ownership_type.clone();
+        
+        emit!(OwnershipTypeUpdated {
+            ulpin_id,
+            owner: land_parcel.owner,
+            ownership_type,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OwnershipTypeUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_historical_registration(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+        historical_timestamp: i64,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!(!land_parcel.is_verified, ErrorCode::AlreadyVerified);
+        require!(
+            historical_timestamp <= Clock::get()?.unix_timestamp,
+            ErrorCode::FutureRegistrationTimestamp
+        );
+        
+        let previous_timestamp = land_parcel.registration_timestamp;
+        land_parcel.registration_timestamp = historical_timestamp;
+        
+        emit!(RegistrationBackdated {
+            ulpin_id,
+            previous_timestamp,
+            historical_timestamp,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::RegistrationBackdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    /// Pays a single destination token account from the treasury token account
+    pub fn withdraw_fees(
+        ctx: Context<WithdrawFeesTo>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            amount <= ctx.accounts.treasury_token_account.amount,
+            ErrorCode::InsufficientTreasuryBalance
+        );
+        
+        let treasury = &ctx.accounts.treasury;
+        let seeds = &[b"treasury".as_ref(), &[treasury.treasury_bump]];
+        let signer = &[&seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: treasury.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+        
+        ctx.accounts.treasury.record_withdrawal(amount);
+        
+        emit!(FeesWithdrawn {
+            beneficiary: ctx.accounts.destination.owner,
+            destination: ctx.accounts.destination.key(),
+            amount,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Pays lamport fees collected by `mint_land_nft_sol` out of the treasury
+    /// PDA. The PDA always keeps its rent-exempt minimum.
+    pub fn withdraw_sol_fees(
+        ctx: Context<WithdrawSolFees>,
+        amount: u64,
+    ) -> Result<()> {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+        let available = treasury_info.lamports().saturating_sub(rent_exempt_minimum);
+        require!(amount <= available, ErrorCode::InsufficientTreasuryBalance);
+        
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+        
+        ctx.accounts.treasury.sol_withdrawn += amount;
+        
+        emit!(SolFeesWithdrawn {
+            destination: ctx.accounts.destination.key(),
+            amount,
+            authority: ctx.accounts.authority.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Pays several beneficiaries from the treasury token account in one
+    /// transaction. Destination token accounts are passed in
+    /// `remaining_accounts` in the same order as `disbursements`.
+    pub fn withdraw_fees_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+        disbursements: Vec<FeeDisbursement>,
+    ) -> Result<()> {
+        require!(
+            !disbursements.is_empty() && disbursements.len() <= MAX_DISBURSEMENTS_PER_BATCH,
+            ErrorCode::InvalidDisbursementBatch
+        );
+        require!(
+            ctx.remaining_accounts.len() == disbursements.len(),
+            ErrorCode::InvalidDisbursementBatch
+        );
+        
+        let mut total: u64 = 0;
+        for disbursement in disbursements.iter() {
+            total = total
+                .checked_add(disbursement.amount)
+                .ok_or(ErrorCode::InsufficientTreasuryBalance)?;
+        }
+        require!(
+            total <= ctx.accounts.treasury_token_account.amount,
+            ErrorCode::InsufficientTreasuryBalance
+        );
+        
+        let treasury = &ctx.accounts.treasury;
+        let seeds = &[b"treasury".as_ref(), &[treasury.treasury_bump]];
+        let signer = &[&seeds[..]];
+        
+        for (disbursement, destination) in disbursements.iter().zip(ctx.remaining_accounts.iter()) {
+            let destination_account = Account::<TokenAccount>::try_from(destination)?;
+            require!(
+                destination_account.owner == disbursement.beneficiary
+                    && destination_account.mint == ctx.accounts.treasury_token_account.mint,
+                ErrorCode::InvalidDisbursementAccount
+            );
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.treasury_token_account.to_account_info(),
+                to: destination.clone(),
+                authority: treasury.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, disbursement.amount)?;
+            
+            emit!(FeesWithdrawn {
+                beneficiary: disbursement.beneficiary,
+                destination: destination.key(),
+                amount: disbursement.amount,
+                authority: ctx.accounts.authority.key(),
+            });
+        }
+        
+        ctx.accounts.treasury.record_withdrawal(total);
+        
+        Ok(())
+    }
+
+    pub fn dedupe_parcels(
+        ctx: Context<DedupeParcels>,
+        canonical_ulpin: String,
+        duplicate_ulpin: String,
+    ) -> Result<()> {
+        let canonical = &mut ctx.accounts.canonical_parcel;
+        let duplicate = &ctx.accounts.duplicate_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        
+        require!(
+            canonical.district == duplicate.district
+                && canonical.taluka == duplicate.taluka
+                && canonical.village == duplicate.village
+                && canonical.owner == duplicate.owner
+                && canonical.bbox.overlaps(&duplicate.bbox),
+            ErrorCode::ParcelsNotDuplicates
+        );
+        require!(
+            !(canonical.nft_minted && duplicate.nft_minted),
+            ErrorCode::DuplicateNFTConflict
+        );
+        
+        // Liens, escrow and history PDAs are seeded by the duplicate's address,
+        // so it can only be closed once nothing is attached to it
+        duplicate.require_no_holds(Clock::get()?.unix_timestamp)?;
+        
+        // Carry any verification and NFT state over before the duplicate is closed
+        let nft_transferred = duplicate.nft_minted && !canonical.nft_minted;
+        if nft_transferred {
+            canonical.nft_minted = true;
+            canonical.nft_mint = duplicate.nft_mint;
+            canonical.metadata_uri = duplicate.metadata_uri.clone();
+            canonical.mint_timestamp = duplicate.mint_timestamp;
+        }
+        if duplicate.is_verified && !canonical.is_verified {
+            canonical.is_verified = true;
+            canonical.verified_at = duplicate.verified_at;
+            canonical.verified_by = duplicate.verified_by;
+        }
+        
+        treasury.land_parcel_count = treasury.land_parcel_count.saturating_sub(1);
+        
+        emit!(ParcelsDeduped {
+            canonical_ulpin,
+            duplicate_ulpin,
+            nft_transferred,
+            refund_to: ctx.accounts.authority.key(),
+        });
+        
+        emit_parcel_state_changed(canonical, ParcelChangeType::Deduplicated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn subdivide_parcel<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubdivideParcel<'info>>,
+        children: Vec<SubdivisionInput>,
+    ) -> Result<()> {
+        require!(
+            children.len() >= 2
+                && children.len() <= MAX_SUBDIVISION_CHILDREN
+                && children.len() == ctx.remaining_accounts.len(),
+            ErrorCode::InvalidSubdivision
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let parent = &mut ctx.accounts.parent_parcel;
+        require!(!parent.subdivided, ErrorCode::ParcelAlreadySubdivided);
+        require!(!parent.merged, ErrorCode::ParcelAlreadyMerged);
+        require!(
+            !parent.transfer_pending && parent.pending_owner.is_none(),
+            ErrorCode::TransferAlreadyPending
+        );
+        require!(!parent.under_arbitration, ErrorCode::ParcelUnderArbitration);
+        require!(!parent.under_dispute, ErrorCode::ParcelUnderDispute);
+        require!(parent.active_liens == 0, ErrorCode::ActiveLienExists);
+        require!(!parent.is_frozen(now), ErrorCode::ParcelFrozen);
+        require!(!parent.is_quarantined(now), ErrorCode::ParcelQuarantined);
+        
+        let total_area = children
+            .iter()
+            .try_fold(0u64, |total, child| total.checked_add(child.area_sqm))
+            .ok_or(ErrorCode::SubdivisionAreaMismatch)?;
+        require!(total_area == parent.area_sqm, ErrorCode::SubdivisionAreaMismatch);
+        
+        let authority = ctx.accounts.authority.key();
+        
+        // Child parcel PDAs are passed in remaining_accounts, in input order, and
+        // inherit the parent's location
+        for (child, account_info) in children.iter().zip(ctx.remaining_accounts.iter()) {
+            require!(child.document_hash != [0u8; 32], ErrorCode::MissingDocumentHash);
+            let input = LandParcelInput {
+                ulpin_id: child.ulpin_id.clone(),
+                area_sqm: child.area_sqm,
+                district: String::new(),
+                taluka: String::new(),
+                village: String::new(),
+                owner: child.owner,
+                document_hash: child.document_hash,
+                bbox: parent.bbox,
+                land_use: parent.land_use,
+            };
+            validate_registration(&input, &ctx.accounts.sanctions_list, &ctx.accounts.jurisdiction)?;
+            check_district_minimum(&ctx.accounts.district_rules, child.area_sqm)?;
+            
+            let child_parcel = LandParcel {
+                district: parent.district,
+                taluka: parent.taluka,
+                village: parent.village,
+                parent_ulpin: parent.ulpin_id,
+                ..LandParcel::registered(&input, now)
+            };
+            create_land_parcel_account(
+                account_info,
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                ctx.program_id,
+                &child_parcel,
+                &child.ulpin_id,
+            )?;
+            
+            emit_parcel_state_changed(&child_parcel, ParcelChangeType::Registered, authority)?;
+        }
+        
+        parent.subdivided = true;
+        ctx.accounts.treasury.land_parcel_count += children.len() as u64;
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = parent.district;
+        district_stats.registered_count += children.len() as u64;
+        district_stats.emit_updated();
+        
+        emit!(ParcelSubdivided {
+            parent_ulpin: String::from_utf8_lossy(&parent.ulpin_id).trim_matches('\0').to_string(),
+            child_count: children.len() as u32,
+            total_area,
+        });
+        
+        emit_parcel_state_changed(parent, ParcelChangeType::Subdivided, authority)?;
+        
+        Ok(())
+    }
+
+    pub fn merge_parcels<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MergeParcels<'info>>,
+        result_ulpin: String,
+        district: String,
+        source_ulpins: Vec<String>,
+        document_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(document_hash != [0u8; 32], ErrorCode::MissingDocumentHash);
+        require!(
+            source_ulpins.len() >= 2
+                && source_ulpins.len() <= MAX_MERGE_SOURCES
+                && source_ulpins.len() == ctx.remaining_accounts.len(),
+            ErrorCode::InvalidMerge
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        // Source parcels are passed in remaining_accounts, in the same order as source_ulpins
+        let mut sources: Vec<Account<'info, LandParcel>> = Vec::with_capacity(source_ulpins.len());
+        for (ulpin_id, account_info) in source_ulpins.iter().zip(ctx.remaining_accounts.iter()) {
+            require!(ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
+            let source = Account::<LandParcel>::try_from(account_info)?;
+            
+            let mut ulpin_bytes = [0u8; 64];
+            ulpin_bytes[..ulpin_id.len()].copy_from_slice(ulpin_id.as_bytes());
+            require!(
+                source.ulpin_id == ulpin_bytes
+                    && !sources.iter().any(|seen| seen.key() == account_info.key()),
+                ErrorCode::InvalidMerge
+            );
+            require!(source.is_verified, ErrorCode::LandNotVerified);
+            require!(!source.merged && !source.subdivided, ErrorCode::ParcelAlreadyMerged);
+            require!(
+                !source.transfer_pending && source.pending_owner.is_none(),
+                ErrorCode::TransferAlreadyPending
+            );
+            require!(!source.under_arbitration, ErrorCode::ParcelUnderArbitration);
+            require!(!source.under_dispute, ErrorCode::ParcelUnderDispute);
+            require!(source.active_liens == 0, ErrorCode::ActiveLienExists);
+            require!(!source.is_frozen(now), ErrorCode::ParcelFrozen);
+            require!(!source.is_quarantined(now), ErrorCode::ParcelQuarantined);
+            
+            require!(source.district == location_seed(&district), ErrorCode::MergeLocationMismatch);
+            if let Some(first) = sources.first() {
+                require_keys_eq!(source.owner, first.owner, ErrorCode::MergeOwnerMismatch);
+                require!(
+                    source.taluka == first.taluka && source.village == first.village,
+                    ErrorCode::MergeLocationMismatch
+                );
+            }
+            sources.push(source);
+        }
+        
+        let total_area = sources
+            .iter()
+            .try_fold(0u64, |total, source| total.checked_add(source.area_sqm))
+            .ok_or(ErrorCode::InvalidArea)?;
+        check_district_minimum(&ctx.accounts.district_rules, total_area)?;
+        
+        let first = &sources[0];
+        let input = LandParcelInput {
+            ulpin_id: result_ulpin.clone(),
+            area_sqm: total_area,
+            district: String::new(),
+            taluka: String::new(),
+            village: String::new(),
+            owner: first.owner,
+            document_hash,
+            bbox: sources[1..]
+                .iter()
+                .fold(first.bbox, |bbox, source| bbox.union(&source.bbox)),
+            land_use: first.land_use,
+        };
+        validate_registration(&input, &ctx.accounts.sanctions_list, &ctx.accounts.jurisdiction)?;
+        
+        let authority = ctx.accounts.authority.key();
+        let result_parcel = &mut ctx.accounts.result_parcel;
+        result_parcel.set_inner(LandParcel {
+            district: first.district,
+            taluka: first.taluka,
+            village: first.village,
+            ..LandParcel::registered(&input, now)
+        });
+        
+        for source in sources.iter_mut() {
+            source.merged = true;
+            source.exit(ctx.program_id)?;
+            emit_parcel_state_changed(source, ParcelChangeType::Merged, authority)?;
+        }
+        
+        ctx.accounts.treasury.land_parcel_count += 1;
+        
+        let district_stats = &mut ctx.accounts.district_stats;
+        district_stats.district = location_seed(&district);
+        district_stats.registered_count += 1;
+        district_stats.emit_updated();
+        
+        emit!(ParcelsMerged {
+            result_ulpin,
+            source_count: sources.len() as u32,
+            total_area,
+        });
+        
+        emit_parcel_state_changed(&ctx.accounts.result_parcel, ParcelChangeType::Registered, authority)?;
+        
+        Ok(())
+    }
+
+    pub fn close_land_parcel(
+        ctx: Context<CloseLandParcel>,
+    ) -> Result<()> {
+        let land_parcel = &ctx.accounts.land_parcel;
+        let treasury = &mut ctx.accounts.treasury;
+        
+        // Never orphan a minted NFT by dropping its parcel record
+        require!(!land_parcel.nft_minted, ErrorCode::CannotCloseMintedParcel);
+        land_parcel.require_no_holds(Clock::get()?.unix_timestamp)?;
+        
+        treasury.land_parcel_count = treasury.land_parcel_count.saturating_sub(1);
+        
+        emit!(LandParcelClosed {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            refund_to: ctx.accounts.authority.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Closed, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_transfer_window(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        require!(start_ts < end_ts, ErrorCode::InvalidTransferWindow);
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.transfer_window = Some(TransferWindow { start_ts, end_ts });
+        
+        emit!(TransferWindowUpdated {
+            ulpin_id,
+            transfer_window: land_parcel.transfer_window.clone(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::TransferWindowUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn clear_transfer_window(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.transfer_window = None;
+        
+        emit!(TransferWindowUpdated {
+            ulpin_id,
+            transfer_window: None,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::TransferWindowUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn add_boundary_point(
+        ctx: Context<AddBoundaryPoint>,
+        lat: i32,
+        lon: i32,
+    ) -> Result<()> {
+        let boundary = &mut ctx.accounts.parcel_boundary;
+        
+        require!(!boundary.finalized, ErrorCode::BoundaryAlreadyFinalized);
+        require!(boundary.vertices.len() < MAX_BOUNDARY_POINTS, ErrorCode::BoundaryFull);
+        
+        boundary.land_parcel = ctx.accounts.land_parcel.key();
+        boundary.vertices.push(BoundaryPoint { lat, lon });
+        
+        Ok(())
+    }
+
+    pub fn finalize_boundary(
+        ctx: Context<FinalizeBoundary>,
+        ulpin_id: String,
+    ) -> Result<()> {
+        let boundary = &mut ctx.accounts.parcel_boundary;
+        
+        require!(!boundary.finalized, ErrorCode::BoundaryAlreadyFinalized);
+        require!(
+            boundary.vertices.len() >= MIN_BOUNDARY_POINTS,
+            ErrorCode::InsufficientBoundaryPoints
+        );
+        
+        boundary.finalized = true;
+        
+        emit!(BoundaryFinalized {
+            ulpin_id,
+            vertex_count: boundary.vertices.len() as u32,
+        });
+        
+        Ok(())
+    }
+
+    pub fn add_easement(
+        ctx: Context<AddEasement>,
+        ulpin_id: String,
+        easement_type: EasementType,
+        beneficiary: Pubkey,
+        description_hash: [u8; 32],
+        blocks_transfer: bool,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.easement_registry;
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!(registry.easements.len() < MAX_EASEMENTS, ErrorCode::EasementRegistryFull);
+        
+        registry.land_parcel = land_parcel.key();
+        registry.easements.push(Easement {
+            easement_type: easement_type.clone(),
+            beneficiary,
+            description_hash,
+            blocks_transfer,
+        });
+        land_parcel.easement_count += 1;
+        if blocks_transfer {
+            land_parcel.transfer_blocking_easements += 1;
+        }
+        
+        emit!(EasementAdded {
+            ulpin_id,
+            easement_type,
+            beneficiary,
+            description_hash,
+            blocks_transfer,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::EasementsUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn remove_easement(
+        ctx: Context<RemoveEasement>,
+        ulpin_id: String,
+        index: u8,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.easement_registry;
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!((index as usize) < registry.easements.len(), ErrorCode::EasementNotFound);
+        
+        let easement = registry.easements.remove(index as usize);
+        land_parcel.easement_count -= 1;
+        if easement.blocks_transfer {
+            land_parcel.transfer_blocking_easements -= 1;
+        }
+        
+        emit!(EasementRemoved {
+            ulpin_id,
+            easement_type: easement.easement_type,
+            beneficiary: easement.beneficiary,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::EasementsUpdated, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn set_occupancy(
+        ctx: Context<SetOccupancy>,
+        ulpin_id: String,
+        occupancy_status: OccupancyStatus,
+        possessor: Option<Pubkey>,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let signer = ctx.accounts.signer.key();
+        
+        require!(
+            signer == land_parcel.owner || signer == ctx.accounts.treasury.authority,
+            ErrorCode::UnauthorizedOccupancyUpdate
+        );
+        
+        land_parcel.occupancy_status = occupancy_status.clone();
+        land_parcel.possessor = possessor;
+        
+        emit!(OccupancyUpdated {
+            ulpin_id,
+            occupancy_status,
+            possessor,
+            updated_by: signer,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::OccupancyUpdated, signer)?;
+        
+        Ok(())
+    }
+
+    pub fn quarantine_parcel(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+        duration_seconds: i64,
+        reason: String,
+    ) -> Result<()> {
+        require!(duration_seconds > 0, ErrorCode::InvalidQuarantineDuration);
+        require!(reason.len() <= MAX_QUARANTINE_REASON_LEN, ErrorCode::QuarantineReasonTooLong);
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.quarantine_until = Clock::get()?.unix_timestamp + duration_seconds;
+        
+        emit!(ParcelQuarantined {
+            ulpin_id,
+            quarantine_until: land_parcel.quarantine_until,
+            reason,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Quarantined, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn lift_quarantine(
+        ctx: Context<AdminUpdateLandParcel>,
+        ulpin_id: String,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        land_parcel.quarantine_until = 0;
+        
+        emit!(QuarantineLifted {
+            ulpin_id,
+            lifted_by: ctx.accounts.authority.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::QuarantineLifted, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn initialize_freeze_authority(
+        ctx: Context<InitializeFreezeAuthority>,
+        freeze_authority_bump: u8,
+    ) -> Result<()> {
+        let freeze_authority = &mut ctx.accounts.freeze_authority;
+        freeze_authority.authority = ctx.accounts.authority.key();
+        freeze_authority.freeze_authority_bump = freeze_authority_bump;
+        freeze_authority.max_freeze_seconds = DEFAULT_MAX_FREEZE_SECONDS;
+        Ok(())
+    }
+
+    pub fn set_max_freeze_duration(
+        ctx: Context<UpdateFreezeAuthority>,
+        max_freeze_seconds: i64,
+    ) -> Result<()> {
+        require!(max_freeze_seconds > 0, ErrorCode::InvalidFreezeDuration);
+        ctx.accounts.freeze_authority.max_freeze_seconds = max_freeze_seconds;
+        Ok(())
+    }
+
+    /// Hands control of the freeze PDA to a new key. The PDA itself stays the
+    /// mints' freeze authority, so no token-level SetAuthority is needed.
+    pub fn transfer_freeze_authority(
+        ctx: Context<UpdateFreezeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let freeze_authority = &mut ctx.accounts.freeze_authority;
+        let old = freeze_authority.authority;
+        freeze_authority.authority = new_authority;
+
+        emit!(FreezeAuthorityRotated {
+            old,
+            new: new_authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn freeze_land_nft(
+        ctx: Context<FreezeLandNFT>,
+        duration_seconds: i64,
+        case_reference: [u8; 32],
+        reason_code: u8,
+        note: String,
+    ) -> Result<()> {
+        let reason = FreezeReason::try_from(reason_code)?;
+        require!(note.len() <= MAX_FREEZE_NOTE_LEN, ErrorCode::FreezeNoteTooLong);
+        require!(
+            duration_seconds > 0 && duration_seconds <= ctx.accounts.freeze_authority.max_freeze_seconds,
+            ErrorCode::InvalidFreezeDuration
+        );
+
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(
+            land_parcel.nft_mint == Some(ctx.accounts.nft_mint.key()),
+            ErrorCode::MintMismatch
+        );
+
+        // A lapsed freeze still holds the token frozen until it is thawed
+        require!(land_parcel.freeze_start_timestamp.is_none(), ErrorCode::AlreadyFrozen);
+
+        let clock = Clock::get()?;
+        require!(!land_parcel.is_quarantined(clock.unix_timestamp), ErrorCode::ParcelQuarantined);
+        land_parcel.freeze_start_timestamp = Some(clock.unix_timestamp);
+        land_parcel.freeze_duration = Some(duration_seconds);
+        land_parcel.case_reference = case_reference;
+        land_parcel.freeze_reason = Some(reason.clone());
+
+        let cpi_accounts = FreezeAccount {
+            account: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let seeds = &[
+            b"freeze_authority".as_ref(),
+            &[ctx.accounts.freeze_authority.freeze_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token_interface::freeze_account(cpi_ctx)?;
+
+        // Convert ulpin_id from [u8; 64] to String for event emission
+        let ulpin_id_string = String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string();
+        
+        emit!(NFTFrozen {
+            ulpin_id: ulpin_id_string,
+            nft_mint: ctx.accounts.nft_mint.key(),
+            freeze_duration: duration_seconds,
+            freeze_start: clock.unix_timestamp,
+            freeze_expiry: clock.unix_timestamp + duration_seconds,
+            case_reference,
+            reason,
+            note,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Frozen, ctx.accounts.authority.key())?;
+
+        Ok(())
+    }
+
+    pub fn thaw_land_nft(ctx: Context<ThawLandNFT>) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(
+            land_parcel.nft_mint == Some(ctx.accounts.nft_mint.key()),
+            ErrorCode::MintMismatch
+        );
+        require!(land_parcel.freeze_start_timestamp.is_some(), ErrorCode::NotFrozen);
+
+        let clock = Clock::get()?;
+        let freeze_start = land_parcel.freeze_start_timestamp.unwrap_or(0);
+        let freeze_duration = land_parcel.freeze_duration.unwrap_or(0);
+
+        require!(
+            clock.unix_timestamp > freeze_start + freeze_duration,
+            ErrorCode::FreezePeriodNotExpired
+        );
+
+        let cpi_accounts = ThawAccount {
+            account: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let seeds = &[
+            b"freeze_authority".as_ref(),
+            &[ctx.accounts.freeze_authority.freeze_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token_interface::thaw_account(cpi_ctx)?;
+
+        land_parcel.freeze_start_timestamp = None;
+        land_parcel.freeze_duration = None;
+        land_parcel.case_reference = [0u8; 32];
+        land_parcel.freeze_reason = None;
+
+        // Convert ulpin_id from [u8; 64] to String for event emission
+        let ulpin_id_string = String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string();
+        
+        emit!(NFTThawed {
+            ulpin_id: ulpin_id_string,
+            nft_mint: ctx.accounts.nft_mint.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Thawed, ctx.accounts.authority.key())?;
+
+        Ok(())
+    }
+
+    /// Thaws every listed parcel frozen for `reason_code` whose freeze has
+    /// expired, or all of them with `override_expiry`. Each parcel takes
+    /// three `remaining_accounts`, in `ulpin_ids` order: the parcel, the
+    /// holder's token account and the NFT mint. Parcels frozen for another
+    /// reason, not frozen, or still inside their freeze are skipped and
+    /// listed in `ParcelsThawedByReason`.
+    pub fn thaw_by_reason_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ThawByReasonBatch<'info>>,
+        reason_code: u8,
+        ulpin_ids: Vec<String>,
+        override_expiry: bool,
+    ) -> Result<()> {
+        let reason = FreezeReason::try_from(reason_code)?;
+        require!(
+            !ulpin_ids.is_empty() && ulpin_ids.len() <= MAX_THAW_BATCH,
+            ErrorCode::BatchTooLarge
+        );
+        require!(
+            ctx.remaining_accounts.len() == ulpin_ids.len() * 3,
+            ErrorCode::InvalidBatchAccounts
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let authority = ctx.accounts.authority.key();
+        let seeds = &[
+            b"freeze_authority".as_ref(),
+            &[ctx.accounts.freeze_authority.freeze_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+        let mut thawed_count: u32 = 0;
+        let mut skipped = Vec::new();
+        
+        for (ulpin_id, accounts) in ulpin_ids.iter().zip(ctx.remaining_accounts.chunks(3)) {
+            require!(ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
+            let mut land_parcel = Account::<LandParcel>::try_from(&accounts[0])?;
+            let token_account = InterfaceAccount::<token_interface::TokenAccount>::try_from(&accounts[1])?;
+            let nft_mint = &accounts[2];
+            
+            let mut ulpin_bytes = [0u8; 64];
+            ulpin_bytes[..ulpin_id.len()].copy_from_slice(ulpin_id.as_bytes());
+            require!(land_parcel.ulpin_id == ulpin_bytes, ErrorCode::InvalidBatchAccounts);
+            require!(
+                land_parcel.nft_mint == Some(nft_mint.key()) && token_account.mint == nft_mint.key(),
+                ErrorCode::MintMismatch
+            );
+            
+            let matches_reason = land_parcel.freeze_start_timestamp.is_some()
+                && land_parcel.freeze_reason.as_ref() == Some(&reason);
+            if !matches_reason || (land_parcel.is_frozen(now) && !override_expiry) {
+                skipped.push(ulpin_id.clone());
+                continue;
+            }
+            
+            token_interface::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ThawAccount {
+                    account: accounts[1].clone(),
+                    mint: nft_mint.clone(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                signer,
+            ))?;
+            
+            land_parcel.freeze_start_timestamp = None;
+            land_parcel.freeze_duration = None;
+            land_parcel.case_reference = [0u8; 32];
+            land_parcel.freeze_reason = None;
+            land_parcel.exit(ctx.program_id)?;
+            thawed_count += 1;
+            
+            emit!(NFTThawed {
+                ulpin_id: ulpin_id.clone(),
+                nft_mint: nft_mint.key(),
+            });
+            
+            emit_parcel_state_changed(&land_parcel, ParcelChangeType::Thawed, authority)?;
+        }
+        
+        emit!(ParcelsThawedByReason {
+            reason,
+            thawed_count,
+            skipped_count: skipped.len() as u32,
+            skipped,
+            override_expiry,
+        });
+        
+        Ok(())
+    }
+
+    pub fn freeze_time_remaining(ctx: Context<FreezeTimeRemaining>) -> Result<i64> {
+        Ok(ctx.accounts.land_parcel.freeze_time_remaining(Clock::get()?.unix_timestamp))
+    }
+
+    /// Releases a freeze before it expires, e.g. when a court orders immediate release
+    pub fn force_thaw_land_nft(ctx: Context<ForceThawLandNFT>, reason: String) -> Result<()> {
+        require!(reason.len() <= MAX_FORCE_THAW_REASON_LEN, ErrorCode::ThawReasonTooLong);
+
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        require!(land_parcel.is_verified, ErrorCode::LandNotVerified);
+        require!(land_parcel.nft_minted, ErrorCode::NFTNotMinted);
+        require!(
+            land_parcel.nft_mint == Some(ctx.accounts.nft_mint.key()),
+            ErrorCode::MintMismatch
+        );
+        require!(land_parcel.freeze_start_timestamp.is_some(), ErrorCode::NotFrozen);
+
+        let cpi_accounts = ThawAccount {
+            account: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let seeds = &[
+            b"freeze_authority".as_ref(),
+            &[ctx.accounts.freeze_authority.freeze_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token_interface::thaw_account(cpi_ctx)?;
+
+        land_parcel.freeze_start_timestamp = None;
+        land_parcel.freeze_duration = None;
+        land_parcel.case_reference = [0u8; 32];
+        land_parcel.freeze_reason = None;
+
+        // Convert ulpin_id from [u8; 64] to String for event emission
+        let ulpin_id_string = String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string();
+        
+        emit!(NFTForceThawed {
+            ulpin_id: ulpin_id_string,
+            nft_mint: ctx.accounts.nft_mint.key(),
+            reason,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::Thawed, ctx.accounts.authority.key())?;
+
+        Ok(())
+    }
+    /// Sums the balances of treasury-owned token accounts passed in
+    /// `remaining_accounts` and emits the per-account breakdown.
+    pub fn emit_consolidated_holdings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EmitConsolidatedHoldings<'info>>,
+    ) -> Result<()> {
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_HOLDINGS_ACCOUNTS,
+            ErrorCode::InvalidHoldingsQuery
+        );
+        
+        let treasury_key = ctx.accounts.treasury.key();
+        let mut holdings = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut total: u64 = 0;
+        for account_info in ctx.remaining_accounts.iter() {
+            let token_account = Account::<TokenAccount>::try_from(account_info)?;
+            require_keys_eq!(
+                token_account.owner,
+                treasury_key,
+                ErrorCode::InvalidTreasuryTokenAccount
+            );
+            require!(
+                !holdings.iter().any(|holding: &TreasuryHolding| holding.token_account == account_info.key()),
+                ErrorCode::InvalidHoldingsQuery
+            );
+            
+            total = total
+                .checked_add(token_account.amount)
+                .ok_or(ErrorCode::InvalidHoldingsQuery)?;
+            holdings.push(TreasuryHolding {
+                token_account: account_info.key(),
+                mint: token_account.mint,
+                amount: token_account.amount,
+            });
+        }
+        
+        emit!(ConsolidatedHoldings {
+            total,
+            holdings,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Emits the mint fees collected so far for each land-use class
+    pub fn emit_landuse_revenue(ctx: Context<EmitLandUseRevenue>) -> Result<()> {
+        let land_use_revenue = &ctx.accounts.land_use_revenue;
+        
+        emit!(LandUseRevenueSnapshot {
+            token_fees: land_use_revenue.token_fees,
+            sol_fees: land_use_revenue.sol_fees,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Records productivity data for an agricultural parcel. The owner or
+    /// the treasury's agriculture authority may sign.
+    pub fn set_agri_data(
+        ctx: Context<SetAgriData>,
+        irrigation_type: u8,
+        soil_class: u8,
+        last_season_yield_kg_per_ha: u64,
+    ) -> Result<()> {
+        let irrigation_type = IrrigationType::try_from(irrigation_type)?;
+        require!((1..=8).contains(&soil_class), ErrorCode::InvalidSoilClass);
+        
+        let land_parcel = &ctx.accounts.land_parcel;
+        let signer = ctx.accounts.signer.key();
+        require!(
+            signer == land_parcel.owner || signer == ctx.accounts.treasury.agriculture_authority,
+            ErrorCode::Unauthorized
+        );
+        require!(
+            land_parcel.land_use == LandUse::Agricultural,
+            ErrorCode::NotAgriculturalParcel
+        );
+        
+        let agri_data = &mut ctx.accounts.agri_data;
+        agri_data.land_parcel = land_parcel.key();
+        agri_data.irrigation_type = irrigation_type;
+        agri_data.soil_class = soil_class;
+        agri_data.last_season_yield_kg_per_ha = last_season_yield_kg_per_ha;
+        agri_data.updated_at = Clock::get()?.unix_timestamp;
+        agri_data.updated_by = signer;
+        
+        emit!(AgriDataUpdated {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            irrigation_type,
+            soil_class,
+            last_season_yield_kg_per_ha,
+            updated_by: signer,
+        });
+        
+        Ok(())
+    }
+
+    /// Read-only view of a parcel for off-chain clients, returned through
+    /// Anchor's return data so callers need not track the account layout
+    pub fn get_parcel_summary(ctx: Context<GetParcelSummary>) -> Result<ParcelSummaryV1> {
+        let land_parcel = &ctx.accounts.land_parcel;
+        let to_string = |bytes: &[u8]| String::from_utf8_lossy(bytes).trim_matches('\0').to_string();
+        
+        Ok(ParcelSummaryV1 {
+            ulpin_id: to_string(&land_parcel.ulpin_id),
+            area_sqm: land_parcel.area_sqm,
+            district: to_string(&land_parcel.district),
+            taluka: to_string(&land_parcel.taluka),
+            village: to_string(&land_parcel.village),
+            owner: land_parcel.owner,
+            is_verified: land_parcel.is_verified,
+            nft_minted: land_parcel.nft_minted,
+            is_frozen: land_parcel.is_frozen(Clock::get()?.unix_timestamp),
+            freeze_reason: land_parcel.freeze_reason.clone(),
+        })
+    }
+
+    /// `get_parcel_summary` with the parcel's land use and, for agricultural
+    /// parcels that have it, their productivity data
+    pub fn get_parcel_summary_v2(ctx: Context<GetParcelSummaryV2>) -> Result<ParcelSummaryV2> {
+        let land_parcel = &ctx.accounts.land_parcel;
+        let to_string = |bytes: &[u8]| String::from_utf8_lossy(bytes).trim_matches('\0').to_string();
+        
+        let agri_data_info = ctx.accounts.agri_data.to_account_info();
+        let agri_data = if agri_data_info.data_is_empty() {
+            None
+        } else {
+            Some(AgriData::try_deserialize(&mut &agri_data_info.try_borrow_data()?[..])?)
+        };
+        
+        Ok(ParcelSummaryV2 {
+            ulpin_id: to_string(&land_parcel.ulpin_id),
+            area_sqm: land_parcel.area_sqm,
+            district: to_string(&land_parcel.district),
+            taluka: to_string(&land_parcel.taluka),
+            village: to_string(&land_parcel.village),
+            owner: land_parcel.owner,
+            is_verified: land_parcel.is_verified,
+            nft_minted: land_parcel.nft_minted,
+            is_frozen: land_parcel.is_frozen(Clock::get()?.unix_timestamp),
+            freeze_reason: land_parcel.freeze_reason.clone(),
+            land_use: land_parcel.land_use,
+            agri_data,
+        })
+    }
+
+    pub fn emit_owner_portfolio<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EmitOwnerPortfolio>,
+        owner: Pubkey,
+        ulpin_ids: Vec<String>,
+    ) -> Result<()> {
+        require!(
+            !ulpin_ids.is_empty()
+                && ulpin_ids.len() <= MAX_PORTFOLIO_PARCELS
+                && ulpin_ids.len() == ctx.remaining_accounts.len(),
+            ErrorCode::InvalidPortfolioQuery
+        );
+        
+        // Parcel accounts are passed in remaining_accounts, in the same order as ulpin_ids
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(ulpin_ids.len());
+        let mut total_area_sqm: u64 = 0;
+        for (ulpin_id, account_info) in ulpin_ids.into_iter().zip(ctx.remaining_accounts.iter()) {
+            require!(ulpin_id.len() <= 64, ErrorCode::InvalidULPINLength);
+            let land_parcel = Account::<LandParcel>::try_from(account_info)?;
+            
+            let mut ulpin_bytes = [0u8; 64];
+            ulpin_bytes[..ulpin_id.len()].copy_from_slice(ulpin_id.as_bytes());
+            require!(
+                land_parcel.ulpin_id == ulpin_bytes && !seen.contains(&account_info.key()),
+                ErrorCode::InvalidPortfolioQuery
+            );
+            require_keys_eq!(land_parcel.owner, owner, ErrorCode::PortfolioOwnerMismatch);
+            seen.push(account_info.key());
+            
+            total_area_sqm = total_area_sqm
+                .checked_add(land_parcel.area_sqm)
+                .ok_or(ErrorCode::InvalidPortfolioQuery)?;
+            
+            emit!(ParcelSummary {
+                ulpin_id,
+                owner,
+                area_sqm: land_parcel.area_sqm,
+                district: land_parcel.district,
+                village: land_parcel.village,
+                is_verified: land_parcel.is_verified,
+                nft_minted: land_parcel.nft_minted,
+                easement_count: land_parcel.easement_count,
+            });
+        }
+        
+        emit!(PortfolioSummary {
+            owner,
+            parcel_count: seen.len() as u32,
+            total_area_sqm,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    pub fn register_arbiter(
+        ctx: Context<RegisterArbiter>,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        let arbiter_account = &mut ctx.accounts.arbiter_account;
+        arbiter_account.arbiter = arbiter;
+        
+        emit!(ArbiterRegistered { arbiter });
+        
+        Ok(())
+    }
+
+    pub fn open_arbitration(
+        ctx: Context<OpenArbitration>,
+        ulpin_id: String,
+        claimant: Pubkey,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let arbitration = &mut ctx.accounts.arbitration;
+        
+        require!(!land_parcel.under_arbitration, ErrorCode::ArbitrationAlreadyOpen);
+        require_keys_neq!(claimant, land_parcel.owner, ErrorCode::InvalidClaimant);
+        
+        arbitration.land_parcel = land_parcel.key();
+        arbitration.claimant = claimant;
+        arbitration.evidence_hash = evidence_hash;
+        arbitration.opened_at = Clock::get()?.unix_timestamp;
+        arbitration.ruling = None;
+        land_parcel.under_arbitration = true;
+        
+        emit!(ArbitrationOpened {
+            ulpin_id,
+            owner: land_parcel.owner,
+            claimant,
+            evidence_hash,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::ArbitrationOpened, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+
+    pub fn submit_arbiter_ruling(
+        ctx: Context<SubmitArbiterRuling>,
+        ulpin_id: String,
+        ruling: ArbitrationRuling,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let arbitration = &mut ctx.accounts.arbitration;
+        
+        require!(land_parcel.under_arbitration, ErrorCode::NoOpenArbitration);
+        
+        let previous_owner = land_parcel.owner;
+        if ruling == ArbitrationRuling::TransferToClaimant {
+            require!(land_parcel.active_liens == 0, ErrorCode::ActiveLienExists);
+            apply_ownership_change(
+                land_parcel,
+                &ctx.accounts.treasury,
+                &mut ctx.accounts.ownership_history,
+                ulpin_id.clone(),
+                arbitration.claimant,
+            )?;
+        }
+        land_parcel.under_arbitration = false;
+        arbitration.ruling = Some(ruling.clone());
+        
+        emit!(ArbitrationRuled {
+            ulpin_id,
+            arbiter: ctx.accounts.arbiter.key(),
+            ruling,
+            previous_owner,
+            owner: land_parcel.owner,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::ArbitrationRuled, ctx.accounts.arbiter.key())?;
+        
+        Ok(())
+    }
+
+    /// Flags a parcel as disputed by the signing claimant. Minting and
+    /// ownership transfers are blocked until the authority resolves it.
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        reason: String,
+    ) -> Result<()> {
+        require!(reason.len() <= MAX_DISPUTE_REASON_LEN, ErrorCode::DisputeReasonTooLong);
+        
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        let claimant = ctx.accounts.claimant.key();
+        
+        require!(!land_parcel.under_dispute, ErrorCode::DisputeAlreadyOpen);
+        require_keys_neq!(claimant, land_parcel.owner, ErrorCode::InvalidClaimant);
+        
+        land_parcel.under_dispute = true;
+        land_parcel.dispute_claimant = Some(claimant);
+        
+        emit!(DisputeOpened {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            claimant,
+            reason,
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::DisputeOpened, claimant)?;
+        
+        Ok(())
+    }
+
+    /// Clears a dispute. `upheld` records whether the claim was found valid;
+    /// any change of ownership that follows goes through the normal transfer
+    /// or arbitration paths.
+    pub fn resolve_dispute(
+        ctx: Context<AdminUpdateLandParcel>,
+        upheld: bool,
+    ) -> Result<()> {
+        let land_parcel = &mut ctx.accounts.land_parcel;
+        
+        require!(land_parcel.under_dispute, ErrorCode::NoOpenDispute);
+        
+        let claimant = land_parcel.dispute_claimant.take().unwrap_or_default();
+        land_parcel.under_dispute = false;
+        
+        emit!(DisputeResolved {
+            ulpin_id: String::from_utf8_lossy(&land_parcel.ulpin_id).trim_matches('\0').to_string(),
+            claimant,
+            upheld,
+            resolved_by: ctx.accounts.authority.key(),
+        });
+        
+        emit_parcel_state_changed(land_parcel, ParcelChangeType::DisputeResolved, ctx.accounts.authority.key())?;
+        
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 8 + 8 + 1 + 1 + 5 + 32 + 5 + 1 + 9 + 1 + 18 + 33 + 1 + 1 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 32 + 8 + 32 + 32,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 4 + MAX_SANCTIONED_PARTIES * 32,
+        seeds = [b"sanctions"],
+        bump
+    )]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSanctionsList<'info> {
+    #[account(mut, seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasuryConfig<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptTreasuryAuthority<'info> {
+    #[account(mut)]
+    pub treasury: Account<'info, Treasury>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct SetOwnerMintExemption<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + 1,
+        seeds = [b"owner_mints", owner.as_ref()],
+        bump
+    )]
+    pub owner_mint_record: Account<'info, OwnerMintRecord>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(district: String, token_account: Pubkey)]
+pub struct SetDistrictTreasury<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 32 + 8 + 8,
+        seeds = [b"district_treasury", location_seed(&district).as_ref()],
+        bump
+    )]
+    pub district_treasury: Account<'info, DistrictTreasury>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    /// Must be withdrawable by the treasury, so fees routed here stay
+    /// part of `current_balance`
+    #[account(
+        address = token_account @ ErrorCode::DistrictTreasuryMismatch,
+        constraint = district_token_account.owner == treasury.key() @ ErrorCode::InvalidTreasuryTokenAccount,
+        constraint = district_token_account.mint == treasury.fee_mint @ ErrorCode::InvalidFeeMint
+    )]
+    pub district_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 + 8 + 2 * LAND_USE_CLASSES,
+        seeds = [b"fee_config"],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeConfig<'info> {
+    #[account(mut, seeds = [b"fee_config"], bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(district: String)]
+pub struct SetDistrictRules<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 8,
+        seeds = [b"district_rules", location_seed(&district).as_ref()],
+        bump
+    )]
+    pub district_rules: Account<'info, DistrictRules>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJurisdiction<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 16,
+        seeds = [b"jurisdiction"],
+        bump
+    )]
+    pub jurisdiction: Account<'info, JurisdictionConfig>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(district: String, taluka: String, village: String)]
+pub struct SetVillageConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 32 + 32 + 1 + 32,
+        seeds = [
+            b"village_config",
+            location_seed(&district).as_ref(),
+            location_seed(&taluka).as_ref(),
+            location_seed(&village).as_ref()
+        ],
+        bump
+    )]
+    pub village_config: Account<'info, VillageConfig>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(firm: Pubkey)]
+pub struct AccreditFirm<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 8,
+        seeds = [b"accredited_firm", firm.as_ref()],
+        bump
+    )]
+    pub accredited_firm: Account<'info, AccreditedFirm>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(ulpin_id: String, area_sqm: u64, district: String)]
+pub struct RegisterLandParcel<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = LandParcel::SPACE,
+        seeds = [b"land_parcel", ulpin_seed(&ulpin_id).as_ref()],
+        bump
+    )]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: jurisdiction PDA; may be uninitialized, in which case the
+    /// Gujarat bounds apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"jurisdiction"], bump)]
+    pub jurisdiction: UncheckedAccount<'info>,
+    /// CHECK: rules PDA for the parcel's district; may be uninitialized when
+    /// the district has no rules, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"district_rules", location_seed(&district).as_ref()], bump)]
+    pub district_rules: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&location_seed(&district)).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(district: String)]
+pub struct RegisterLandParcelsBatch<'info> {
+    #[account(mut)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: jurisdiction PDA; may be uninitialized, in which case the
+    /// Gujarat bounds apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"jurisdiction"], bump)]
+    pub jurisdiction: UncheckedAccount<'info>,
+    /// CHECK: rules PDA for the batch's district; may be uninitialized when
+    /// the district has no rules, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"district_rules", location_seed(&district).as_ref()], bump)]
+    pub district_rules: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&location_seed(&district)).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintLandNFT<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, constraint = is_non_fungible(&nft_mint) @ ErrorCode::NotAnNFT)]
+    pub nft_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + 32 + 4 + 1,
+        seeds = [b"owner_mints", land_parcel.owner.as_ref()],
+        bump
+    )]
+    pub owner_mint_record: Account<'info, OwnerMintRecord>,
+    /// CHECK: PDA for the parcel's district; may be uninitialized when the
+    /// district has no treasury of its own, and is deserialized in the
+    /// handler otherwise.
+    #[account(
+        mut,
+        seeds = [b"district_treasury", land_parcel.district.as_ref()],
+        bump
+    )]
+    pub district_treasury: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&land_parcel.district).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LandUseRevenue::SPACE,
+        seeds = [b"land_use_revenue"],
+        bump
+    )]
+    pub land_use_revenue: Account<'info, LandUseRevenue>,
+    /// CHECK: fee schedule PDA; may be uninitialized, in which case the
+    /// default fees apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+    pub exemption_authority: Option<Signer<'info>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintLandNFTSol<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(constraint = is_non_fungible(&nft_mint) @ ErrorCode::NotAnNFT)]
+    pub nft_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + 32 + 4 + 1,
+        seeds = [b"owner_mints", land_parcel.owner.as_ref()],
+        bump
+    )]
+    pub owner_mint_record: Account<'info, OwnerMintRecord>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&land_parcel.district).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LandUseRevenue::SPACE,
+        seeds = [b"land_use_revenue"],
+        bump
+    )]
+    pub land_use_revenue: Account<'info, LandUseRevenue>,
+    /// CHECK: PDA for the parcel's district; may be uninitialized when the
+    /// district has no treasury of its own, and is deserialized in the
+    /// handler otherwise.
+    #[account(
+        mut,
+        seeds = [b"district_treasury", land_parcel.district.as_ref()],
+        bump
+    )]
+    pub district_treasury: UncheckedAccount<'info>,
+    /// CHECK: fee schedule PDA; may be uninitialized, in which case the
+    /// default fees apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+    pub exemption_authority: Option<Signer<'info>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordNftBurn<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(constraint = land_parcel.nft_mint == Some(nft_mint.key()) @ ErrorCode::MintMismatch)]
+    pub nft_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReissueLandNFT<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(constraint = is_non_fungible(&new_mint) @ ErrorCode::NotAnNFT)]
+    pub new_mint: Account<'info, Mint>,
+    #[account(
+        constraint = owner_token_account.mint == new_mint.key()
+            && owner_token_account.owner == land_parcel.owner
+            && owner_token_account.amount == 1 @ ErrorCode::TokenAccountOwnerMismatch
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateNftStandard<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        mut,
+        constraint = land_parcel.nft_mint == Some(old_mint.key()) @ ErrorCode::MintMismatch
+    )]
+    pub old_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = owner_old_token_account.mint == old_mint.key()
+            && owner_old_token_account.owner == owner.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
+    pub owner_old_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        owner = token_2022_program.key() @ ErrorCode::NotToken2022Mint,
+        constraint = new_mint.decimals == 0
+            && new_mint.supply == 0
+            && new_mint.mint_authority == COption::Some(owner.key()) @ ErrorCode::NotAnNFT,
+        constraint = new_mint.freeze_authority == old_mint.freeze_authority @ ErrorCode::FreezeAuthorityMismatch
+    )]
+    pub new_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(
+        mut,
+        constraint = owner_new_token_account.mint == new_mint.key()
+            && owner_new_token_account.owner == owner.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
+    pub owner_new_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+    #[account(constraint = owner.key() == land_parcel.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyLandParcel<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub owner: Option<Signer<'info>>,
+    #[account(seeds = [b"accredited_firm", authority.key().as_ref()], bump)]
+    pub accredited_firm: Option<Account<'info, AccreditedFirm>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + MAX_VERIFIERS * (32 + 8),
+        seeds = [b"verification", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub verification_state: Account<'info, VerificationState>,
+    #[account(seeds = [b"verifiers"], bump)]
+    pub verifier_registry: Option<Account<'info, VerifierRegistry>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&land_parcel.district).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVerifierRegistry<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 4 + MAX_VERIFIERS * 32,
+        seeds = [b"verifiers"],
+        bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerificationQuorum<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 4 + MAX_VERIFIERS * 32,
+        seeds = [b"verifiers"],
+        bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateLandOwnership<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: PDA for the parcel's village; may be uninitialized when the
+    /// village has no config, and is deserialized in the handler otherwise.
+    #[account(
+        seeds = [
+            b"village_config",
+            land_parcel.district.as_ref(),
+            land_parcel.taluka.as_ref(),
+            land_parcel.village.as_ref()
+        ],
+        bump
+    )]
+    pub village_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = OwnershipHistory::SPACE,
+        seeds = [
+            b"ownership_history",
+            land_parcel.key().as_ref(),
+            land_parcel.ownership_history_page().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ownership_history: Account<'info, OwnershipHistory>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub protection_authority: Option<Signer<'info>>,
+    pub panchayat: Option<Signer<'info>>,
+    pub karta: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeOwnership<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: PDA for the parcel's village; may be uninitialized when the
+    /// village has no config, and is deserialized in the handler otherwise.
+    #[account(
+        seeds = [
+            b"village_config",
+            land_parcel.district.as_ref(),
+            land_parcel.taluka.as_ref(),
+            land_parcel.village.as_ref()
+        ],
+        bump
+    )]
+    pub village_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OwnershipHistory::SPACE,
+        seeds = [
+            b"ownership_history",
+            land_parcel.key().as_ref(),
+            land_parcel.ownership_history_page().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ownership_history: Account<'info, OwnershipHistory>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub protection_authority: Option<Signer<'info>>,
+    pub panchayat: Option<Signer<'info>>,
+    pub karta: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ContestOwnershipChange<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeTransfer<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: PDA for the parcel's village; may be uninitialized when the
+    /// village has no config, and is deserialized in the handler otherwise.
+    #[account(
+        seeds = [
+            b"village_config",
+            land_parcel.district.as_ref(),
+            land_parcel.taluka.as_ref(),
+            land_parcel.village.as_ref()
+        ],
+        bump
+    )]
+    pub village_config: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + 32 + 32 + 32 + 8 + 8,
+        seeds = [b"pending_transfer", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+    pub seller: Signer<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub protection_authority: Option<Signer<'info>>,
+    pub panchayat: Option<Signer<'info>>,
+    pub karta: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptTransfer<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: PDA for the parcel's village; may be uninitialized when the
+    /// village has no config, and is deserialized in the handler otherwise.
+    #[account(
+        seeds = [
+            b"village_config",
+            land_parcel.district.as_ref(),
+            land_parcel.taluka.as_ref(),
+            land_parcel.village.as_ref()
+        ],
+        bump
+    )]
+    pub village_config: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"pending_transfer", land_parcel.key().as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::InvalidBuyer,
+        has_one = seller @ ErrorCode::Unauthorized
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = OwnershipHistory::SPACE,
+        seeds = [
+            b"ownership_history",
+            land_parcel.key().as_ref(),
+            land_parcel.ownership_history_page().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ownership_history: Account<'info, OwnershipHistory>,
+    /// CHECK: receives the sale price; must be the seller recorded in escrow
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub protection_authority: Option<Signer<'info>>,
+    pub panchayat: Option<Signer<'info>>,
+    pub karta: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTransfer<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"pending_transfer", land_parcel.key().as_ref()],
+        bump,
+        has_one = buyer @ ErrorCode::InvalidBuyer
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+    /// CHECK: refunded the deposit and rent; must be the buyer recorded in escrow
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterLien<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        init,
+        payer = lender,
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 4,
+        seeds = [b"lien", land_parcel.key().as_ref(), lender.key().as_ref()],
+        bump
+    )]
+    pub lien: Account<'info, Lien>,
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub lender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseLien<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        mut,
+        close = lender,
+        seeds = [b"lien", land_parcel.key().as_ref(), lender.key().as_ref()],
+        bump,
+        has_one = lender @ ErrorCode::InvalidLender
+    )]
+    pub lien: Account<'info, Lien>,
+    #[account(mut)]
+    pub lender: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferSubsurfaceRights<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetParcelProtection<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = heritage_authority @ ErrorCode::UnauthorizedHeritageAuthority)]
+    pub treasury: Account<'info, Treasury>,
+    pub heritage_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub claimant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdminUpdateLandParcel<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReallocLandParcel<'info> {
+    /// CHECK: may predate the current layout; owner and discriminator are checked in the handler
+    #[account(mut, owner = crate::ID @ ErrorCode::InvalidLandParcelAccount)]
+    pub land_parcel: UncheckedAccount<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == treasury.key() @ ErrorCode::InvalidTreasuryTokenAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSolFees<'info> {
+    #[account(mut, seeds = [b"treasury"], bump, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFeesTo<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == treasury.key() @ ErrorCode::InvalidTreasuryTokenAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = destination.mint == treasury_token_account.mint @ ErrorCode::InvalidDisbursementAccount
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SubdivideParcel<'info> {
+    #[account(mut)]
+    pub parent_parcel: Account<'info, LandParcel>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: jurisdiction PDA; may be uninitialized, in which case the
+    /// Gujarat bounds apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"jurisdiction"], bump)]
+    pub jurisdiction: UncheckedAccount<'info>,
+    /// CHECK: rules PDA for the parent's district; may be uninitialized when
+    /// the district has no rules, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"district_rules", parent_parcel.district.as_ref()], bump)]
+    pub district_rules: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&parent_parcel.district).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(district: String)]
+pub struct VerifyParcelsBatch<'info> {
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"verifiers"], bump)]
+    pub verifier_registry: Option<Account<'info, VerifierRegistry>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&location_seed(&district)).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(result_ulpin: String, district: String)]
+pub struct MergeParcels<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = LandParcel::SPACE,
+        seeds = [b"land_parcel", ulpin_seed(&result_ulpin).as_ref()],
+        bump
+    )]
+    pub result_parcel: Account<'info, LandParcel>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"sanctions"], bump)]
+    pub sanctions_list: Account<'info, SanctionsList>,
+    /// CHECK: jurisdiction PDA; may be uninitialized, in which case the
+    /// Gujarat bounds apply, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"jurisdiction"], bump)]
+    pub jurisdiction: UncheckedAccount<'info>,
+    /// CHECK: rules PDA for the sources' district; may be uninitialized when
+    /// the district has no rules, and is deserialized in the handler otherwise.
+    #[account(seeds = [b"district_rules", location_seed(&district).as_ref()], bump)]
+    pub district_rules: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DistrictStats::SPACE,
+        seeds = [b"district_stats", district_stats_seed(&location_seed(&district)).as_ref()],
+        bump
+    )]
+    pub district_stats: Account<'info, DistrictStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLandParcel<'info> {
+    #[account(mut, close = authority)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DedupeParcels<'info> {
+    #[account(mut)]
+    pub canonical_parcel: Account<'info, LandParcel>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = duplicate_parcel.key() != canonical_parcel.key() @ ErrorCode::ParcelsNotDuplicates
+    )]
+    pub duplicate_parcel: Account<'info, LandParcel>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddBoundaryPoint<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + MAX_BOUNDARY_POINTS * 8 + 1,
+        seeds = [b"boundary", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub parcel_boundary: Account<'info, ParcelBoundary>,
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddEasement<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + MAX_EASEMENTS * (1 + 32 + 32 + 1),
+        seeds = [b"easements", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub easement_registry: Account<'info, EasementRegistry>,
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveEasement<'info> {
+    #[account(
+        mut,
+        seeds = [b"easements", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub easement_registry: Account<'info, EasementRegistry>,
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeBoundary<'info> {
+    #[account(
+        mut,
+        seeds = [b"boundary", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub parcel_boundary: Account<'info, ParcelBoundary>,
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetOccupancy<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    pub treasury: Account<'info, Treasury>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmitConsolidatedHoldings<'info> {
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct EmitOwnerPortfolio {}
+
+#[derive(Accounts)]
+pub struct EmitLandUseRevenue<'info> {
+    #[account(seeds = [b"land_use_revenue"], bump)]
+    pub land_use_revenue: Account<'info, LandUseRevenue>,
+}
+
+#[derive(Accounts)]
+pub struct GetParcelSummary<'info> {
+    pub land_parcel: Account<'info, LandParcel>,
+}
+
+#[derive(Accounts)]
+pub struct GetParcelSummaryV2<'info> {
+    pub land_parcel: Account<'info, LandParcel>,
+    /// CHECK: agri data PDA for the parcel; may be uninitialized, and is
+    /// deserialized in the handler otherwise.
+    #[account(seeds = [b"agri_data", land_parcel.key().as_ref()], bump)]
+    pub agri_data: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAgriData<'info> {
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = AgriData::SPACE,
+        seeds = [b"agri_data", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub agri_data: Account<'info, AgriData>,
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(arbiter: Pubkey)]
+pub struct RegisterArbiter<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32,
+        seeds = [b"arbiter", arbiter.as_ref()],
+        bump
+    )]
+    pub arbiter_account: Account<'info, Arbiter>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenArbitration<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 32 + 32 + 8 + 2,
+        seeds = [b"arbitration", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub arbitration: Account<'info, Arbitration>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitArbiterRuling<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(
+        mut,
+        seeds = [b"arbitration", land_parcel.key().as_ref()],
+        bump
+    )]
+    pub arbitration: Account<'info, Arbitration>,
+    #[account(seeds = [b"arbiter", arbiter.key().as_ref()], bump)]
+    pub arbiter_account: Account<'info, Arbiter>,
+    pub arbiter: Signer<'info>,
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OwnershipHistory::SPACE,
+        seeds = [
+            b"ownership_history",
+            land_parcel.key().as_ref(),
+            land_parcel.ownership_history_page().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ownership_history: Account<'info, OwnershipHistory>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFreezeAuthority<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 8,
+        seeds = [b"freeze_authority"],
+        bump
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFreezeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"freeze_authority"],
+        bump = freeze_authority.freeze_authority_bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeLandNFT<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub nft_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(
+        seeds = [b"freeze_authority"],
+        bump = freeze_authority.freeze_authority_bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ThawLandNFT<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub nft_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(
+        seeds = [b"freeze_authority"],
+        bump = freeze_authority.freeze_authority_bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ThawByReasonBatch<'info> {
+    #[account(
+        seeds = [b"freeze_authority"],
+        bump = freeze_authority.freeze_authority_bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeTimeRemaining<'info> {
+    pub land_parcel: Account<'info, LandParcel>,
+}
+
+#[derive(Accounts)]
+pub struct ForceThawLandNFT<'info> {
+    #[account(mut)]
+    pub land_parcel: Account<'info, LandParcel>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub nft_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(
+        seeds = [b"freeze_authority"],
+        bump = freeze_authority.freeze_authority_bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub freeze_authority: Account<'info, FreezeAuthorityPDA>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[account]
+pub struct Treasury {
+    pub authority: Pubkey,
+    pub treasury_bump: u8,
+    pub total_fees_collected: u64,
+    pub land_parcel_count: u64,
+    pub is_active: bool,
+    pub owner_ack_required: bool,
+    pub mint_settlement_seconds: Option<u32>,
+    pub heritage_authority: Pubkey,
+    pub max_nfts_per_owner: Option<u32>,
+    pub reverify_on_transfer: bool,
+    pub auto_sweep_threshold: Option<u64>,
+    pub enforce_owner_token_account: bool,
+    pub rapid_transfer_limit: Option<RapidTransferLimit>,
+    pub pending_authority: Option<Pubkey>,
+    pub paused_flags: u8,
+    /// Distinct registered verifiers needed to verify a parcel; 0 keeps
+    /// single-signer verification
+    pub required_approvals: u8,
+    /// Lamports collected through `mint_land_nft_sol`, kept apart from
+    /// token-denominated fees
+    pub sol_fees_collected: u64,
+    /// Token fees paid out through `withdraw_fees` and `withdraw_fees_batch`
+    pub total_withdrawn: u64,
+    /// Token fees still held: collected minus withdrawn
+    pub current_balance: u64,
+    /// Challenge window before a staged ownership change can be finalized
+    pub transfer_delay_seconds: i64,
+    /// Schema version; appended so older layouts keep their field offsets
+    pub version: u8,
+    /// How long a verification vote counts towards the quorum; 0 never expires
+    pub vote_validity_seconds: i64,
+    /// Minimum seconds between metadata updates on a parcel; 0 disables the limit
+    pub min_metadata_update_interval: i64,
+    /// Token that mint fees are paid in; token fees are refused until set
+    pub fee_mint: Pubkey,
+    /// Lamport fees paid out through `withdraw_sol_fees`
+    pub sol_withdrawn: u64,
+    /// Agriculture department signer allowed to record `AgriData`
+    pub agriculture_authority: Pubkey,
+}
+
+impl Treasury {
+    /// Tokens sent to the vault directly are never counted as collected, so a
+    /// withdrawal can exceed `current_balance`; it bottoms out at zero.
+    pub fn record_withdrawal(&mut self, amount: u64) {
+        self.total_withdrawn += amount;
+        self.current_balance = self.current_balance.saturating_sub(amount);
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused_flags & flag != 0
+    }
+}
+
+/// Surveyed boundary polygon for a parcel. Coordinates are fixed-point
+/// degrees scaled by 1e6.
+#[account]
+pub struct ParcelBoundary {
+    pub land_parcel: Pubkey,
+    pub vertices: Vec<BoundaryPoint>,
+    pub finalized: bool,
+}
+
+#[account]
+pub struct FreezeAuthorityPDA {
+    pub authority: Pubkey,
+    pub freeze_authority_bump: u8,
+    pub max_freeze_seconds: i64,
+}
+
+#[account]
+pub struct AccreditedFirm {
+    pub firm: Pubkey,
+    pub accredited_until: i64,
+}
+
+/// Rights of way and utility access granted over a parcel
+#[account]
+pub struct EasementRegistry {
+    pub land_parcel: Pubkey,
+    pub easements: Vec<Easement>,
+}
+
+#[account]
+pub struct Arbiter {
+    pub arbiter: Pubkey,
+}
+
+/// Latest arbitration over a parcel; reused when a new claim is opened
+#[account]
+pub struct Arbitration {
+    pub land_parcel: Pubkey,
+    pub claimant: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub opened_at: i64,
+    pub ruling: Option<ArbitrationRuling>,
+}
+
+#[account]
+pub struct FeeConfig {
+    pub base_fee: u64,
+    pub per_sqm_fee: u64,
+    pub class_multipliers_bps: [u16; LAND_USE_CLASSES],
+}
+
+/// Area within which parcels may be registered
+#[account]
+pub struct JurisdictionConfig {
+    pub bounds: BoundingBox,
+}
+
+/// Registration rules that vary by district
+#[account]
+pub struct DistrictRules {
+    pub district: [u8; 32],
+    pub min_area_sqm: u64,
+}
+
+#[account]
+pub struct VillageConfig {
+    pub district: [u8; 32],
+    pub taluka: [u8; 32],
+    pub village: [u8; 32],
+    pub requires_panchayat_approval: bool,
+    pub panchayat_signer: Pubkey,
+}
+
+#[account]
+pub struct SanctionsList {
+    pub blocked: Vec<Pubkey>,
+}
+
+impl SanctionsList {
+    pub fn is_sanctioned(&self, party: &Pubkey) -> bool {
+        self.blocked.contains(party)
+    }
+}
+
+/// Officers whose approvals count towards the verification quorum
+#[account]
+pub struct VerifierRegistry {
+    pub verifiers: Vec<Pubkey>,
+}
+
+impl VerifierRegistry {
+    pub fn is_verifier(&self, verifier: &Pubkey) -> bool {
+        self.verifiers.contains(verifier)
+    }
+}
+
+/// Approvals collected so far for a parcel's pending verification
+#[account]
+pub struct VerificationState {
+    pub land_parcel: Pubkey,
+    pub approvals: Vec<VerificationVote>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerificationVote {
+    pub verifier: Pubkey,
+    pub approved_at: i64,
+}
+
+/// An escrowed sale awaiting the buyer's acceptance. Holds the price in
+/// lamports on top of its own rent.
+#[account]
+pub struct PendingTransfer {
+    pub land_parcel: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub proposed_at: i64,
+}
+
+/// A lender's registered charge against a parcel, seeded by parcel and
+/// lender so each lender holds at most one lien per parcel.
+#[account]
+pub struct Lien {
+    pub land_parcel: Pubkey,
+    pub lender: Pubkey,
+    pub amount: u64,
+    pub maturity: i64,
+    pub registered_at: i64,
+    /// Rank among the parcel's charges in registration order; 1 is the most
+    /// senior and a released lien's number is never reused
+    pub priority: u32,
+}
+
+/// One page of a parcel's ownership chain. Pages are seeded by
+/// `ownership_history_count / OWNERSHIP_HISTORY_PAGE_SIZE`, so the chain
+/// reads back in order by walking pages from 0.
+#[account]
+pub struct OwnershipHistory {
+    pub land_parcel: Pubkey,
+    pub page: u32,
+    pub entries: Vec<OwnershipHistoryEntry>,
+}
+
+impl OwnershipHistory {
+    pub const SPACE: usize = 8 + 32 + 4 + 4 + OWNERSHIP_HISTORY_PAGE_SIZE * (32 + 8);
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OwnershipHistoryEntry {
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Running per-district counts so dashboards can read one account instead
+/// of scanning every parcel. Subdivided children and merged parcels count
+/// as registrations, and a parcel re-verified after a transfer counts again.
+#[account]
+pub struct DistrictStats {
+    pub district: [u8; 32],
+    pub registered_count: u64,
+    pub verified_count: u64,
+    pub minted_count: u64,
+}
+
+impl DistrictStats {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8;
+
+    pub fn emit_updated(&self) {
+        emit!(DistrictStatsUpdated {
+            district: self.district,
+            registered_count: self.registered_count,
+            verified_count: self.verified_count,
+            minted_count: self.minted_count,
+        });
+    }
+}
+
+#[account]
+pub struct DistrictTreasury {
+    pub district: [u8; 32],
+    pub token_account: Pubkey,
+    pub total_collected: u64,
+    /// Lamport fees from the district's parcels; held by the treasury PDA
+    pub sol_collected: u64,
+}
+
+/// Mint fees attributed to each land-use class, indexed by `LandUse` code.
+/// Token and lamport fees are different units, so they are kept apart.
+#[account]
+pub struct LandUseRevenue {
+    pub token_fees: [u64; LAND_USE_CLASSES],
+    pub sol_fees: [u64; LAND_USE_CLASSES],
+}
+
+impl LandUseRevenue {
+    pub const SPACE: usize = 8 + 8 * LAND_USE_CLASSES + 8 * LAND_USE_CLASSES;
+}
+
+/// Productivity data for an agricultural parcel
+#[account]
+pub struct AgriData {
+    pub land_parcel: Pubkey,
+    pub irrigation_type: IrrigationType,
+    /// Land capability class, I to VIII
+    pub soil_class: u8,
+    pub last_season_yield_kg_per_ha: u64,
+    pub updated_at: i64,
+    pub updated_by: Pubkey,
+}
+
+impl AgriData {
+    pub const SPACE: usize = 8 + 32 + 1 + 1 + 8 + 8 + 32;
+}
+
+#[account]
+pub struct OwnerMintRecord {
+    pub owner: Pubkey,
+    pub minted_count: u32,
+    pub exempt: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LandParcel {
+    pub ulpin_id: [u8; 64],
+    pub area_sqm: u64,
+    pub district: [u8; 32],
+    pub taluka: [u8; 32],
+    pub village: [u8; 32],
+    pub owner: Pubkey,
+    pub registration_timestamp: i64,
+    pub is_verified: bool,
+    pub nft_minted: bool,
+    pub freeze_start_timestamp: Option<i64>,
+    pub freeze_duration: Option<i64>,
+    pub mint_timestamp: Option<i64>,
+    pub protected: bool,
+    pub protection_authority: Pubkey,
+    pub transfer_window: Option<TransferWindow>,
+    pub verified_at: Option<i64>,
+    pub verified_by: Option<Pubkey>,
+    pub occupancy_status: OccupancyStatus,
+    pub possessor: Option<Pubkey>,
+    pub quarantine_until: i64,
+    /// Court case number attached to the active freeze; all zeroes when unset
+    pub case_reference: [u8; 32],
+    pub under_arbitration: bool,
+    /// Holder of water and mineral rights; `owner` holds the surface rights
+    pub subsurface_owner: Pubkey,
+    pub easement_count: u8,
+    pub transfer_blocking_easements: u8,
+    /// Timestamps of the most recent ownership transfers; zero slots are unused
+    pub recent_transfers: [i64; MAX_TRACKED_TRANSFERS],
+    pub ownership_type: OwnershipType,
+    pub freeze_reason: Option<FreezeReason>,
+    /// Set once the parcel has been split; it can no longer change hands
+    pub subdivided: bool,
+    /// ULPIN of the parcel this one was split from; all zeroes when unset
+    pub parent_ulpin: [u8; 64],
+    /// Set once the parcel has been consolidated into a merged parcel
+    pub merged: bool,
+    /// SHA-256 of the scanned 7/12 extract; all zeroes until recorded
+    pub document_hash: [u8; 32],
+    /// Current NFT metadata URI; empty until minted
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String,
+    pub nft_mint: Option<Pubkey>,
+    /// Transfers recorded across all `OwnershipHistory` pages
+    pub ownership_history_count: u32,
+    pub bbox: BoundingBox,
+    pub under_dispute: bool,
+    pub dispute_claimant: Option<Pubkey>,
+    pub land_use: LandUse,
+    /// Set while a `PendingTransfer` escrow exists for the parcel
+    pub transfer_pending: bool,
+    /// Owner-to-be while a time-locked transfer is in its challenge window
+    pub pending_owner: Option<Pubkey>,
+    pub pending_owner_effective_at: i64,
+    /// Schema version; appended so older layouts keep their field offsets.
+    /// Accounts grown by `realloc_land_parcel` read back as 0 here.
+    pub version: u8,
+    /// Liens registered against the parcel and not yet released
+    pub active_liens: u8,
+    /// When the metadata URI was last updated; 0 if never
+    pub last_metadata_update: i64,
+    /// Liens ever registered against the parcel; the next lien's priority
+    pub lien_sequence: u32,
+    /// Owner when the pending change was staged; it only applies if they
+    /// still hold the parcel at finalization
+    pub pending_owner_staged_from: Pubkey,
+}
+
+impl LandParcel {
+    pub fn ownership_history_page(&self) -> u32 {
+        self.ownership_history_count / OWNERSHIP_HISTORY_PAGE_SIZE as u32
+    }
+
+    /// Discriminator, the fields as sized by `InitSpace`, and the 32 spare
+    /// bytes parcel accounts have always been allocated with
+    pub const SPACE: usize = 8 + LandParcel::INIT_SPACE + 32;
+
+    /// State of a freshly registered parcel
+    pub fn registered(input: &LandParcelInput, registration_timestamp: i64) -> Self {
+        let mut ulpin_bytes = [0u8; 64];
+        ulpin_bytes[..input.ulpin_id.len()].copy_from_slice(input.ulpin_id.as_bytes());
+        
+        Self {
+            ulpin_id: ulpin_bytes,
+            area_sqm: input.area_sqm,
+            district: location_seed(&input.district),
+            taluka: location_seed(&input.taluka),
+            village: location_seed(&input.village),
+            owner: input.owner,
+            registration_timestamp,
+            is_verified: false,
+            nft_minted: false,
+            freeze_start_timestamp: None,
+            freeze_duration: None,
+            mint_timestamp: None,
+            protected: false,
+            protection_authority: Pubkey::default(),
+            transfer_window: None,
+            verified_at: None,
+            verified_by: None,
+            occupancy_status: OccupancyStatus::OwnerOccupied,
+            possessor: None,
+            quarantine_until: 0,
+            case_reference: [0u8; 32],
+            under_arbitration: false,
+            subsurface_owner: input.owner,
+            easement_count: 0,
+            transfer_blocking_easements: 0,
+            recent_transfers: [0; MAX_TRACKED_TRANSFERS],
+            ownership_type: OwnershipType::Individual,
+            freeze_reason: None,
+            subdivided: false,
+            parent_ulpin: [0u8; 64],
+            merged: false,
+            document_hash: input.document_hash,
+            metadata_uri: String::new(),
+            nft_mint: None,
+            ownership_history_count: 0,
+            bbox: input.bbox,
+            under_dispute: false,
+            dispute_claimant: None,
+            land_use: input.land_use,
+            transfer_pending: false,
+            pending_owner: None,
+            pending_owner_effective_at: 0,
+            version: CURRENT_VERSION,
+            active_liens: 0,
+            last_metadata_update: 0,
+            lien_sequence: 0,
+            pending_owner_staged_from: Pubkey::default(),
+        }
+    }
+
+    pub fn is_quarantined(&self, now: i64) -> bool {
+        now < self.quarantine_until
+    }
+
+    /// Fails if a lien, pending transfer, proceeding or freeze still rests on
+    /// the parcel, which would be lost if its account were closed.
+    pub fn require_no_holds(&self, now: i64) -> Result<()> {
+        require!(self.active_liens == 0, ErrorCode::ActiveLienExists);
+        require!(
+            !self.transfer_pending && self.pending_owner.is_none(),
+            ErrorCode::TransferAlreadyPending
+        );
+        require!(!self.under_arbitration, ErrorCode::ParcelUnderArbitration);
+        require!(!self.under_dispute, ErrorCode::ParcelUnderDispute);
+        require!(!self.is_frozen(now), ErrorCode::ParcelFrozen);
+        require!(!self.is_quarantined(now), ErrorCode::ParcelQuarantined);
+        Ok(())
+    }
+
+    pub fn is_frozen(&self, now: i64) -> bool {
+        match (self.freeze_start_timestamp, self.freeze_duration) {
+            (Some(start), Some(duration)) => now <= start + duration,
+            _ => false,
+        }
+    }
+
+    /// Seconds until the freeze can be thawed; 0 when not frozen
+    pub fn freeze_time_remaining(&self, now: i64) -> i64 {
+        match (self.freeze_start_timestamp, self.freeze_duration) {
+            (Some(start), Some(duration)) => (start + duration - now).max(0),
+            _ => 0,
+        }
+    }
+}
+
+/// Land-use classification; passed to `register_land_parcel` and
+/// `reclassify_parcel` as its numeric code
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum LandUse {
+    Agricultural,
+    Residential,
+    Commercial,
+    Industrial,
+    Forest,
+    Other,
+}
+
+impl TryFrom<u8> for LandUse {
+    type Error = Error;
+
+    fn try_from(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(LandUse::Agricultural),
+            1 => Ok(LandUse::Residential),
+            2 => Ok(LandUse::Commercial),
+            3 => Ok(LandUse::Industrial),
+            4 => Ok(LandUse::Forest),
+            5 => Ok(LandUse::Other),
+            _ => err!(ErrorCode::InvalidLandUse),
+        }
+    }
+}
+
+/// How an agricultural parcel is watered; passed to `set_agri_data` as its
+/// numeric code
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IrrigationType {
+    Rainfed,
+    Canal,
+    Borewell,
+    Drip,
+    Sprinkler,
+}
+
+impl TryFrom<u8> for IrrigationType {
+    type Error = Error;
+
+    fn try_from(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(IrrigationType::Rainfed),
+            1 => Ok(IrrigationType::Canal),
+            2 => Ok(IrrigationType::Borewell),
+            3 => Ok(IrrigationType::Drip),
+            4 => Ok(IrrigationType::Sprinkler),
+            _ => err!(ErrorCode::InvalidIrrigationType),
+        }
+    }
+}
+
+/// Why a parcel was frozen; passed to `freeze_land_nft` as its numeric code
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum FreezeReason {
+    Dispute,
+    CourtOrder,
+    FraudInvestigation,
+    Administrative,
+    TaxDefault,
+}
+
+impl TryFrom<u8> for FreezeReason {
+    type Error = Error;
+
+    fn try_from(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(FreezeReason::Dispute),
+            1 => Ok(FreezeReason::CourtOrder),
+            2 => Ok(FreezeReason::FraudInvestigation),
+            3 => Ok(FreezeReason::Administrative),
+            4 => Ok(FreezeReason::TaxDefault),
+            _ => err!(ErrorCode::InvalidFreezeReason),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ParcelChangeType {
+    Registered,
+    NftMinted,
+    Verified,
+    OwnershipTransferred,
+    SubsurfaceRightsTransferred,
+    ProtectionUpdated,
+    OwnershipTypeUpdated,
+    RegistrationBackdated,
+    Deduplicated,
+    TransferWindowUpdated,
+    EasementsUpdated,
+    OccupancyUpdated,
+    Quarantined,
+    QuarantineLifted,
+    ArbitrationOpened,
+    ArbitrationRuled,
+    Closed,
+    Subdivided,
+    Merged,
+    DocumentHashUpdated,
+    MetadataUpdated,
+    DisputeOpened,
+    DisputeResolved,
+    Reclassified,
+    TransferProposed,
+    TransferCancelled,
+    OwnershipChangeStaged,
+    OwnershipChangeContested,
+    Migrated,
+    LienRegistered,
+    LienReleased,
+    Frozen,
+    Thawed,
+    NftBurned,
+    NftReissued,
+    NftStandardMigrated,
+}
+
+/// Hindu Undivided Family parcels are held jointly and managed by the karta
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum OwnershipType {
+    Individual,
+    Huf { karta: Pubkey },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum EasementType {
+    RightOfWay,
+    UtilityAccess,
+    Drainage,
+    Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Easement {
+    pub easement_type: EasementType,
+    pub beneficiary: Pubkey,
+    pub description_hash: [u8; 32],
+    pub blocks_transfer: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ArbitrationRuling {
+    TransferToClaimant,
+    UpholdOwner,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum OccupancyStatus {
+    OwnerOccupied,
+    Tenanted,
+    Vacant,
+    Disputed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BoundaryPoint {
+    pub lat: i32,
+    pub lon: i32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LandParcelInput {
+    pub ulpin_id: String,
+    pub area_sqm: u64,
+    pub district: String,
+    pub taluka: String,
+    pub village: String,
+    pub owner: Pubkey,
+    pub document_hash: [u8; 32],
+    pub bbox: BoundingBox,
+    pub land_use: LandUse,
+}
+
+/// Rough parcel extent in degrees scaled by 1e6
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, InitSpace)]
+pub struct BoundingBox {
+    pub min_lat: i32,
+    pub min_lon: i32,
+    pub max_lat: i32,
+    pub max_lon: i32,
+}
+
+impl BoundingBox {
+    pub const GUJARAT: BoundingBox = BoundingBox {
+        min_lat: GUJARAT_MIN_LAT,
+        min_lon: GUJARAT_MIN_LON,
+        max_lat: GUJARAT_MAX_LAT,
+        max_lon: GUJARAT_MAX_LON,
+    };
+
+    pub fn is_valid(&self) -> bool {
+        self.min_lat < self.max_lat && self.min_lon < self.max_lon
+    }
+
+    pub fn contains(&self, other: &BoundingBox) -> bool {
+        other.min_lat >= self.min_lat
+            && other.max_lat <= self.max_lat
+            && other.min_lon >= self.min_lon
+            && other.max_lon <= self.max_lon
+    }
+
+    pub fn overlaps(&self, other: &BoundingBox) -> bool {
+        self.min_lat <= other.max_lat
+            && other.min_lat <= self.max_lat
+            && self.min_lon <= other.max_lon
+            && other.min_lon <= self.max_lon
+    }
+
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min_lat: self.min_lat.min(other.min_lat),
+            min_lon: self.min_lon.min(other.min_lon),
+            max_lat: self.max_lat.max(other.max_lat),
+            max_lon: self.max_lon.max(other.max_lon),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubdivisionInput {
+    pub ulpin_id: String,
+    pub area_sqm: u64,
+    pub owner: Pubkey,
+    /// Hash of the partition deed or survey for this plot
+    pub document_hash: [u8; 32],
+}
+
+/// Stable parcel view returned by `get_parcel_summary`. Layout changes go
+/// into a new versioned struct so existing clients keep decoding this one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParcelSummaryV1 {
+    pub ulpin_id: String,
+    pub area_sqm: u64,
+    pub district: String,
+    pub taluka: String,
+    pub village: String,
+    pub owner: Pubkey,
+    pub is_verified: bool,
+    pub nft_minted: bool,
+    pub is_frozen: bool,
+    pub freeze_reason: Option<FreezeReason>,
+}
+
+/// `ParcelSummaryV1` plus land use and agricultural productivity data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParcelSummaryV2 {
+    pub ulpin_id: String,
+    pub area_sqm: u64,
+    pub district: String,
+    pub taluka: String,
+    pub village: String,
+    pub owner: Pubkey,
+    pub is_verified: bool,
+    pub nft_minted: bool,
+    pub is_frozen: bool,
+    pub freeze_reason: Option<FreezeReason>,
+    pub land_use: LandUse,
+    pub agri_data: Option<AgriData>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RapidTransferLimit {
+    pub max_transfers: u8,
+    pub window_seconds: i64,
+    pub review_seconds: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TransferWindow {
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TreasuryHolding {
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeDisbursement {
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MetadataURIUpdated {
+    pub ulpin_id: String,
+    pub old_uri: String,
+    pub new_uri: String,
+}
+
+#[event]
+pub struct DocumentHashUpdated {
+    pub ulpin_id: String,
+    pub old_hash: [u8; 32],
+    pub new_hash: [u8; 32],
+}
+
+#[event]
+pub struct ParcelsMerged {
+    pub result_ulpin: String,
+    pub source_count: u32,
+    pub total_area: u64,
+}
+
+#[event]
+pub struct ParcelSubdivided {
+    pub parent_ulpin: String,
+    pub child_count: u32,
+    pub total_area: u64,
+}
+
+#[event]
+pub struct LandParcelClosed {
+    pub ulpin_id: String,
+    pub refund_to: Pubkey,
+}
+
+#[event]
+pub struct LandParcelsBatchRegistered {
+    pub count: u32,
+    pub first_ulpin: String,
+    pub last_ulpin: String,
+}
+
+#[event]
+pub struct LandParcelRegistered {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub area_sqm: u64,
+    pub registration_timestamp: i64,
+    pub bbox: BoundingBox,
+}
+
+#[event]
+pub struct LandNFTBurnRecorded {
+    pub ulpin_id: String,
+    pub nft_mint: Pubkey,
+}
+
+#[event]
+pub struct LandNFTReissued {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+}
+
+#[event]
+pub struct NFTStandardMigrated {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+}
+
+#[event]
+pub struct NFTMinted {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub nft_mint: Pubkey,
+    pub metadata_uri: String,
+    pub fee_paid: u64,
+    pub exemption_bps: u16,
+    pub discount: u64,
+    /// Land-use multiplier applied to the area fee, in basis points
+    pub class_multiplier_bps: u16,
+    pub land_use: LandUse,
+}
+
+#[event]
+pub struct ParcelsThawedByReason {
+    pub reason: FreezeReason,
+    pub thawed_count: u32,
+    pub skipped_count: u32,
+    /// ULPINs frozen for another reason, not frozen, or not yet expired
+    pub skipped: Vec<String>,
+    pub override_expiry: bool,
+}
+
+#[event]
+pub struct ParcelsBatchVerified {
+    pub verifier: Pubkey,
+    pub verified_count: u32,
+    pub skipped_count: u32,
+    /// ULPINs that were already verified
+    pub skipped: Vec<String>,
+}
+
+#[event]
+pub struct LandParcelVerified {
+    pub ulpin_id: String,
+    pub verifier: Pubkey,
+    pub owner_ack: Option<Pubkey>,
+    pub accredited_firm: bool,
+    pub verification_timestamp: i64,
+}
 
-#[account]
-pub struct LandParcel {
-    pub ulpin_id: [u8; 64],
+#[event]
+pub struct VerificationApproved {
+    pub ulpin_id: String,
+    pub verifier: Pubkey,
+    pub approvals_so_far: u8,
+}
+
+#[event]
+pub struct VerifierAdded {
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct VerifierRemoved {
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct VerificationQuorumUpdated {
+    pub verifiers: Vec<Pubkey>,
+    pub required_approvals: u8,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub ulpin_id: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub transfer_timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipHistoryAppended {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub page: u32,
+    /// Position in the parcel's full chain, counting from 0 across pages
+    pub index: u32,
+}
+
+#[event]
+pub struct VerificationResetOnTransfer {
+    pub ulpin_id: String,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct OccupancyUpdated {
+    pub ulpin_id: String,
+    pub occupancy_status: OccupancyStatus,
+    pub possessor: Option<Pubkey>,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct AutoFreezeTriggered {
+    pub ulpin_id: String,
+    pub transfer_count: u8,
+    pub window_seconds: i64,
+    pub frozen_until: i64,
+}
+
+#[event]
+pub struct ParcelQuarantined {
+    pub ulpin_id: String,
+    pub quarantine_until: i64,
+    pub reason: String,
+}
+
+#[event]
+pub struct QuarantineLifted {
+    pub ulpin_id: String,
+    pub lifted_by: Pubkey,
+}
+
+#[event]
+pub struct ParcelProtectionUpdated {
+    pub ulpin_id: String,
+    pub protected: bool,
+    pub protection_authority: Pubkey,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct RegistrationBackdated {
+    pub ulpin_id: String,
+    pub previous_timestamp: i64,
+    pub historical_timestamp: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub base_fee: u64,
+    pub per_sqm_fee: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ClassFeeMultipliersUpdated {
+    pub class_multipliers_bps: [u16; LAND_USE_CLASSES],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TreasuryPauseUpdated {
+    pub paused_flags: u8,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TreasuryAuthorityTransferred {
+    pub old: Pubkey,
+    pub new: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgriDataUpdated {
+    pub ulpin_id: String,
+    pub irrigation_type: IrrigationType,
+    pub soil_class: u8,
+    pub last_season_yield_kg_per_ha: u64,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct LandUseRevenueSnapshot {
+    pub token_fees: [u64; LAND_USE_CLASSES],
+    pub sol_fees: [u64; LAND_USE_CLASSES],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasurySweepRecommended {
+    pub treasury_token_account: Pubkey,
+    pub available_balance: u64,
+    pub threshold: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub beneficiary: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct SolFeesWithdrawn {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ParcelsDeduped {
+    pub canonical_ulpin: String,
+    pub duplicate_ulpin: String,
+    pub nft_transferred: bool,
+    pub refund_to: Pubkey,
+}
+
+#[event]
+pub struct TransferWindowUpdated {
+    pub ulpin_id: String,
+    pub transfer_window: Option<TransferWindow>,
+}
+
+#[event]
+pub struct ConsolidatedHoldings {
+    pub total: u64,
+    pub holdings: Vec<TreasuryHolding>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParcelSummary {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
     pub area_sqm: u64,
     pub district: [u8; 32],
-    pub taluka: [u8; 32],
     pub village: [u8; 32],
-    pub owner: Pubkey,
-    pub registration_timestamp: i64,
     pub is_verified: bool,
     pub nft_minted: bool,
-    pub freeze_start_timestamp: Option<i64>,
-    pub freeze_duration: Option<i64>,
+    pub easement_count: u8,
 }
 
 #[event]
-pub struct LandParcelRegistered {
+pub struct PortfolioSummary {
+    pub owner: Pubkey,
+    pub parcel_count: u32,
+    pub total_area_sqm: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FirmAccredited {
+    pub firm: Pubkey,
+    pub accredited_until: i64,
+}
+
+#[event]
+pub struct VillageConfigUpdated {
+    pub district: String,
+    pub taluka: String,
+    pub village: String,
+    pub requires_panchayat_approval: bool,
+    pub panchayat_signer: Pubkey,
+}
+
+#[event]
+pub struct SanctionedPartyAdded {
+    pub party: Pubkey,
+}
+
+#[event]
+pub struct SanctionedPartyRemoved {
+    pub party: Pubkey,
+}
+
+#[event]
+pub struct DistrictTreasuryUpdated {
+    pub district: String,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct OwnerMintExemptionUpdated {
+    pub owner: Pubkey,
+    pub exempt: bool,
+}
+
+#[event]
+pub struct ParcelStateChanged {
+    pub ulpin_id: String,
+    pub change_type: ParcelChangeType,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTypeUpdated {
     pub ulpin_id: String,
     pub owner: Pubkey,
-    pub area_sqm: u64,
-    pub registration_timestamp: i64,
+    pub ownership_type: OwnershipType,
 }
 
 #[event]
-pub struct NFTMinted {
+pub struct HufTransferAuthorized {
+    pub ulpin_id: String,
+    pub karta: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct EasementAdded {
+    pub ulpin_id: String,
+    pub easement_type: EasementType,
+    pub beneficiary: Pubkey,
+    pub description_hash: [u8; 32],
+    pub blocks_transfer: bool,
+}
+
+#[event]
+pub struct EasementRemoved {
+    pub ulpin_id: String,
+    pub easement_type: EasementType,
+    pub beneficiary: Pubkey,
+}
+
+#[event]
+pub struct SubsurfaceRightsTransferred {
+    pub ulpin_id: String,
+    pub surface_owner: Pubkey,
+    pub previous_subsurface_owner: Pubkey,
+    pub new_subsurface_owner: Pubkey,
+    pub transfer_timestamp: i64,
+}
+
+#[event]
+pub struct ArbiterRegistered {
+    pub arbiter: Pubkey,
+}
+
+#[event]
+pub struct ArbitrationOpened {
+    pub ulpin_id: String,
+    pub owner: Pubkey,
+    pub claimant: Pubkey,
+    pub evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct ArbitrationRuled {
+    pub ulpin_id: String,
+    pub arbiter: Pubkey,
+    pub ruling: ArbitrationRuling,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct JurisdictionUpdated {
+    pub bounds: BoundingBox,
+}
+
+#[event]
+pub struct DistrictRulesUpdated {
+    pub district: String,
+    pub min_area_sqm: u64,
+}
+
+#[event]
+pub struct DistrictStatsUpdated {
+    pub district: [u8; 32],
+    pub registered_count: u64,
+    pub verified_count: u64,
+    pub minted_count: u64,
+}
+
+#[event]
+pub struct OwnershipChangeStaged {
+    pub ulpin_id: String,
+    pub current_owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct OwnershipChangeContested {
     pub ulpin_id: String,
     pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct LienRegistered {
+    pub ulpin_id: String,
+    pub lender: Pubkey,
+    pub amount: u64,
+    pub maturity: i64,
+    pub priority: u32,
+}
+
+#[event]
+pub struct LienReleased {
+    pub ulpin_id: String,
+    pub lender: Pubkey,
+    pub amount: u64,
+    pub priority: u32,
+}
+
+#[event]
+pub struct TransferProposed {
+    pub ulpin_id: String,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct TransferAccepted {
+    pub ulpin_id: String,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct TransferCancelled {
+    pub ulpin_id: String,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct LandParcelMigrated {
+    pub ulpin_id: String,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct LandParcelRealloc {
+    pub ulpin_id: String,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+#[event]
+pub struct ParcelReclassified {
+    pub ulpin_id: String,
+    pub previous_use: LandUse,
+    pub new_use: LandUse,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub ulpin_id: String,
+    pub claimant: Pubkey,
+    pub reason: String,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub ulpin_id: String,
+    pub claimant: Pubkey,
+    pub upheld: bool,
+    pub resolved_by: Pubkey,
+}
+
+#[event]
+pub struct BoundaryFinalized {
+    pub ulpin_id: String,
+    pub vertex_count: u32,
+}
+
+#[event]
+pub struct FreezeAuthorityRotated {
+    pub old: Pubkey,
+    pub new: Pubkey,
+}
+
+#[event]
+pub struct NFTFrozen {
+    pub ulpin_id: String,
     pub nft_mint: Pubkey,
-    pub metadata_uri: String,
-    pub fee_paid: u64,
+    pub freeze_duration: i64,
+    pub freeze_start: i64,
+    pub freeze_expiry: i64,
+    pub case_reference: [u8; 32],
+    pub reason: FreezeReason,
+    pub note: String,
 }
 
 #[event]
-pub struct LandParcelVerified {
+pub struct NFTThawed {
     pub ulpin_id: String,
-    pub verifier: Pubkey,
-    pub verification_timestamp: i64,
+    pub nft_mint: Pubkey,
 }
 
 #[event]
-pub struct OwnershipTransferred {
+pub struct NFTForceThawed {
     pub ulpin_id: String,
-    pub previous_owner: Pubkey,
-    pub new_owner: Pubkey,
-    pub transfer_timestamp: i64,
+    pub nft_mint: Pubkey,
+    pub reason: String,
 }
 
 #[error_code]
 pub enum ErrorCode {
     #[msg("ULPIN ID must be 64 characters or less")]
     InvalidULPINLength,
+    #[msg("ULPIN ID cannot be empty")]
+    EmptyULPIN,
     #[msg("Land area must be greater than zero")]
     InvalidArea,
     #[msg("Metadata URI must be 200 characters or less")]
@@ -291,4 +6025,230 @@ pub enum ErrorCode {
     AlreadyVerified,
     #[msg("NFT must be minted before ownership transfer")]
     NFTNotMinted,
+    #[msg("Signer is not the treasury authority")]
+    Unauthorized,
+    #[msg("Current parcel owner must co-sign verification")]
+    OwnerAckRequired,
+    #[msg("Minted NFT has not settled yet; try the transfer again later")]
+    MintNotSettled,
+    #[msg("Signer is not the heritage authority")]
+    UnauthorizedHeritageAuthority,
+    #[msg("Protected parcel transfers require the protection authority's signature")]
+    ProtectionAuthorityRequired,
+    #[msg("Historical registration timestamp cannot be in the future")]
+    FutureRegistrationTimestamp,
+    #[msg("Treasury token account is not owned by the treasury")]
+    InvalidTreasuryTokenAccount,
+    #[msg("Requested withdrawal exceeds the treasury balance")]
+    InsufficientTreasuryBalance,
+    #[msg("Disbursement batch is empty, too large, or missing destination accounts")]
+    InvalidDisbursementBatch,
+    #[msg("Destination token account does not match the beneficiary or fee mint")]
+    InvalidDisbursementAccount,
+    #[msg("Parcels do not describe the same location and owner")]
+    ParcelsNotDuplicates,
+    #[msg("Both parcels have minted NFTs and cannot be deduplicated automatically")]
+    DuplicateNFTConflict,
+    #[msg("Transfer window start must be before its end")]
+    InvalidTransferWindow,
+    #[msg("Parcel can only be transferred within its allowed transfer window")]
+    OutsideTransferWindow,
+    #[msg("Owner has reached the maximum number of minted land NFTs")]
+    OwnerMintLimitReached,
+    #[msg("Parcel boundary has already been finalized")]
+    BoundaryAlreadyFinalized,
+    #[msg("Parcel boundary has reached the maximum number of vertices")]
+    BoundaryFull,
+    #[msg("A boundary polygon needs at least 3 vertices")]
+    InsufficientBoundaryPoints,
+    #[msg("District name must be 32 bytes or less")]
+    InvalidDistrictName,
+    #[msg("Fee token account does not match the district treasury")]
+    DistrictTreasuryMismatch,
+    #[msg("Only the parcel owner or treasury authority can update occupancy")]
+    UnauthorizedOccupancyUpdate,
+    #[msg("Quarantine duration must be positive")]
+    InvalidQuarantineDuration,
+    #[msg("Quarantine reason must be 128 characters or less")]
+    QuarantineReasonTooLong,
+    #[msg("Parcel is quarantined and cannot be modified")]
+    ParcelQuarantined,
+    #[msg("Sanctions list is full")]
+    SanctionsListFull,
+    #[msg("Party is not on the sanctions list")]
+    SanctionedPartyNotFound,
+    #[msg("Owner or recipient is on the sanctions list")]
+    SanctionedParty,
+    #[msg("Holdings query needs between 1 and 16 distinct treasury token accounts")]
+    InvalidHoldingsQuery,
+    #[msg("District, taluka and village names must be 32 bytes or less")]
+    InvalidLocationName,
+    #[msg("Transfers in this village require the panchayat's co-signature")]
+    PanchayatApprovalRequired,
+    #[msg("Surveying firm's accreditation has expired")]
+    AccreditationExpired,
+    #[msg("Token account is not owned by the parcel owner")]
+    TokenAccountOwnerMismatch,
+    #[msg("Portfolio query needs between 1 and 16 distinct parcels matching their ULPIN IDs")]
+    InvalidPortfolioQuery,
+    #[msg("Parcel is not owned by the portfolio owner")]
+    PortfolioOwnerMismatch,
+    #[msg("Parcel already has an open arbitration")]
+    ArbitrationAlreadyOpen,
+    #[msg("Parcel has no open arbitration")]
+    NoOpenArbitration,
+    #[msg("Claimant cannot be the current owner")]
+    InvalidClaimant,
+    #[msg("Parcel is under arbitration and cannot be transferred")]
+    ParcelUnderArbitration,
+    #[msg("Subsurface rights holder must be set before minting")]
+    SubsurfaceOwnerNotSet,
+    #[msg("Parcel already has the maximum number of easements")]
+    EasementRegistryFull,
+    #[msg("No easement exists at that index")]
+    EasementNotFound,
+    #[msg("An easement on this parcel blocks ownership transfers")]
+    EasementBlocksTransfer,
+    #[msg("Rapid transfer limit needs 1 to 7 transfers and positive window and review periods")]
+    InvalidRapidTransferLimit,
+    #[msg("Transfers of joint-family parcels require the karta's signature")]
+    KartaSignatureRequired,
+    #[msg("No treasury authority handoff is pending")]
+    NoPendingAuthority,
+    #[msg("This operation is currently paused")]
+    OperationPaused,
+    #[msg("Pause flags contain unknown bits")]
+    InvalidPauseFlags,
+    #[msg("Only the current owner or the treasury authority can transfer a parcel")]
+    UnauthorizedOwnershipTransfer,
+    #[msg("Freeze reason code is out of range")]
+    InvalidFreezeReason,
+    #[msg("Parcel is frozen and cannot change owner until thawed")]
+    ParcelFrozen,
+    #[msg("Registration batch must contain between 1 and 5 parcels")]
+    BatchTooLarge,
+    #[msg("Batch accounts must be the parcel PDAs in input order")]
+    InvalidBatchAccounts,
+    #[msg("Parcels with a minted NFT cannot be closed")]
+    CannotCloseMintedParcel,
+    #[msg("Subdivision needs between 2 and 5 child parcels passed in order")]
+    InvalidSubdivision,
+    #[msg("Child parcel areas must add up to the parent's area")]
+    SubdivisionAreaMismatch,
+    #[msg("Parcel has already been subdivided")]
+    ParcelAlreadySubdivided,
+    #[msg("Subdivided parcels cannot be transferred")]
+    SubdividedParcelNotTransferable,
+    #[msg("Merge needs between 2 and 5 distinct source parcels passed in order")]
+    InvalidMerge,
+    #[msg("All merged parcels must have the same owner")]
+    MergeOwnerMismatch,
+    #[msg("All merged parcels must be in the same district, taluka and village")]
+    MergeLocationMismatch,
+    #[msg("Parcel has already been merged or subdivided")]
+    ParcelAlreadyMerged,
+    #[msg("Merged parcels cannot be transferred")]
+    MergedParcelNotTransferable,
+    #[msg("Parcel has no document hash recorded")]
+    MissingDocumentHash,
+    #[msg("Signer is not a registered verifier")]
+    UnauthorizedVerifier,
+    #[msg("Verifier has already approved this parcel")]
+    AlreadyApproved,
+    #[msg("Verifier registry is full")]
+    VerifierRegistryFull,
+    #[msg("Required approvals exceed the number of registered verifiers")]
+    InvalidQuorum,
+    #[msg("Verifier is not in the registry")]
+    VerifierNotFound,
+    #[msg("Fee exemption cannot exceed 10000 basis points")]
+    InvalidExemption,
+    #[msg("Bounding box must have min < max and lie within Gujarat")]
+    InvalidCoordinates,
+    #[msg("Parcel is under dispute")]
+    ParcelUnderDispute,
+    #[msg("Parcel already has an open dispute")]
+    DisputeAlreadyOpen,
+    #[msg("Parcel has no open dispute")]
+    NoOpenDispute,
+    #[msg("Dispute reason is too long")]
+    DisputeReasonTooLong,
+    #[msg("NFT mint must have 0 decimals, a supply of 1 and no mint authority")]
+    NotAnNFT,
+    #[msg("Batch verification is unavailable while a quorum or owner acknowledgement is required")]
+    BatchVerificationUnavailable,
+    #[msg("Parcel area is below the district's minimum plot size")]
+    AreaBelowDistrictMinimum,
+    #[msg("Unknown land-use code")]
+    InvalidLandUse,
+    #[msg("Parcel already has a pending ownership transfer")]
+    TransferAlreadyPending,
+    #[msg("Buyer does not match the pending transfer or is the current owner")]
+    InvalidBuyer,
+    #[msg("The transfer's challenge window has not elapsed")]
+    TransferDelayNotElapsed,
+    #[msg("Parcel has no staged ownership change")]
+    NoPendingOwnershipChange,
+    #[msg("Transfer delay cannot be negative")]
+    InvalidTransferDelay,
+    #[msg("Account is already at the current version")]
+    NothingToMigrate,
+    #[msg("Account cannot be shrunk below its current size")]
+    AccountShrinkNotAllowed,
+    #[msg("Account is not a land parcel")]
+    InvalidLandParcelAccount,
+    #[msg("Parcel has an active lien")]
+    ActiveLienExists,
+    #[msg("Lender does not match the signer or is the parcel owner")]
+    InvalidLender,
+    #[msg("Lien amount must be greater than zero")]
+    InvalidLienAmount,
+    #[msg("Lien maturity must be in the future")]
+    InvalidLienMaturity,
+    #[msg("Parcel already has the maximum number of active liens")]
+    TooManyLiens,
+    #[msg("Vote validity cannot be negative")]
+    InvalidVoteValidity,
+    #[msg("Metadata was updated too recently")]
+    MetadataUpdateTooFrequent,
+    #[msg("Metadata update interval cannot be negative")]
+    InvalidMetadataUpdateInterval,
+    #[msg("Every parcel in a batch must belong to the batch's district")]
+    BatchDistrictMismatch,
+    #[msg("Freeze period has not expired yet")]
+    FreezePeriodNotExpired,
+    #[msg("Thaw reason must be 128 bytes or less")]
+    ThawReasonTooLong,
+    #[msg("Freeze note must be 200 bytes or less")]
+    FreezeNoteTooLong,
+    #[msg("Parcel is already frozen")]
+    AlreadyFrozen,
+    #[msg("Parcel is not frozen")]
+    NotFrozen,
+    #[msg("Freeze duration must be positive and within the configured maximum")]
+    InvalidFreezeDuration,
+    #[msg("NFT mint does not belong to this parcel")]
+    MintMismatch,
+    #[msg("Parcel's NFT still has supply and has not been burned")]
+    NFTNotBurned,
+    #[msg("Parcel has never been minted, so there is no NFT to reissue")]
+    NFTNeverMinted,
+    #[msg("Fees must be paid into an account of the treasury's fee mint")]
+    InvalidFeeMint,
+    #[msg("Mint fee does not fit in a u64")]
+    FeeOverflow,
+    #[msg("Parcel has changed hands since the ownership change was staged")]
+    StagedOwnerChanged,
+    #[msg("Parcel lies outside the configured jurisdiction")]
+    OutsideJurisdiction,
+    #[msg("Agricultural data can only be recorded for agricultural parcels")]
+    NotAgriculturalParcel,
+    #[msg("Unknown irrigation type code")]
+    InvalidIrrigationType,
+    #[msg("Soil class must be a land capability class from 1 to 8")]
+    InvalidSoilClass,
+    #[msg("New mint must belong to the Token-2022 program")]
+    NotToken2022Mint,
+    #[msg("New mint must keep the old mint's freeze authority")]
+    FreezeAuthorityMismatch,
 }
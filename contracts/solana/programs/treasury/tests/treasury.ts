@@ -3,7 +3,19 @@ import { Program } from "@coral-xyz/anchor";
 import { UlpinTreasury } from "../target/types/ulpin_treasury";
 import { expect } from "chai";
 import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  AuthorityType,
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+  getMint,
+  setAuthority,
+  burn,
+} from "@solana/spl-token";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 describe("ulpin-treasury", () => {
   // Configure the client to use the local cluster.
@@ -15,10 +27,258 @@ describe("ulpin-treasury", () => {
 
   let treasuryPDA: PublicKey;
   let treasuryBump: number;
+  const [sanctionsListPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("sanctions")],
+    program.programId
+  );
+  const [feeConfigPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_config")],
+    program.programId
+  );
+  const [landUseRevenuePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("land_use_revenue")],
+    program.programId
+  );
+  const [jurisdictionPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("jurisdiction")],
+    program.programId
+  );
+  const [verifierRegistryPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("verifiers")],
+    program.programId
+  );
+  const documentHash = Array(32).fill(1);
+  // Central Ahmedabad, in degrees scaled by 1e6
+  const bbox = { minLat: 23_000_000, minLon: 72_500_000, maxLat: 23_100_000, maxLon: 72_600_000 };
+  // Numeric land-use codes accepted by register_land_parcel and reclassify_parcel
+  const LandUse = { agricultural: 0, residential: 1, commercial: 2, industrial: 3, forest: 4, other: 5 };
   let treasuryTokenAccount: PublicKey;
   let mint: PublicKey;
   let userTokenAccount: PublicKey;
 
+  const findLandParcelPDA = (ulpinId: string): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
+      program.programId
+    )[0];
+
+  const findOwnerMintRecordPDA = (owner: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("owner_mints"), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  // Seeded by the keccak hash of the zero-padded district name as stored on the parcel
+  const findDistrictStatsPDA = (district: string | number[]): PublicKey => {
+    const padded = Buffer.alloc(32);
+    if (typeof district === "string") {
+      padded.write(district);
+    } else {
+      Buffer.from(district).copy(padded);
+    }
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("district_stats"), Buffer.from(keccak_256(padded))],
+      program.programId
+    )[0];
+  };
+
+  const findDistrictRulesPDA = (district: string): PublicKey => {
+    const padded = Buffer.alloc(32);
+    padded.write(district);
+    return PublicKey.findProgramAddressSync([Buffer.from("district_rules"), padded], program.programId)[0];
+  };
+
+  const districtStatsFor = async (ulpinId: string): Promise<PublicKey> =>
+    findDistrictStatsPDA((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).district);
+
+  const findDistrictTreasuryPDA = (district: string | number[]): PublicKey => {
+    const padded = Buffer.alloc(32);
+    if (typeof district === "string") {
+      padded.write(district);
+    } else {
+      Buffer.from(district).copy(padded);
+    }
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("district_treasury"), padded],
+      program.programId
+    )[0];
+  };
+
+  const districtTreasuryFor = async (ulpinId: string): Promise<PublicKey> =>
+    findDistrictTreasuryPDA((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).district);
+
+  const findVillageConfigPDA = (district = "Ahmedabad", taluka = "City", village = "Village1"): PublicKey => {
+    const padded = (name: string) => {
+      const bytes = Buffer.alloc(32);
+      bytes.write(name);
+      return bytes;
+    };
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("village_config"), padded(district), padded(taluka), padded(village)],
+      program.programId
+    )[0];
+  };
+
+  const findVerificationStatePDA = (landParcel: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("verification"), landParcel.toBuffer()],
+      program.programId
+    )[0];
+
+  const registerParcel = async (
+    ulpinId: string,
+    owner: PublicKey,
+    { areaSqm = 1000, district = "Ahmedabad", village = "Village1", landUse = LandUse.agricultural } = {}
+  ) => {
+    const landParcelPDA = findLandParcelPDA(ulpinId);
+    await program.methods
+      .registerLandParcel(
+        ulpinId,
+        new anchor.BN(areaSqm),
+        district,
+        "City",
+        village,
+        owner,
+        documentHash,
+        bbox.minLat,
+        bbox.minLon,
+        bbox.maxLat,
+        bbox.maxLon,
+        landUse
+      )
+      .accounts({
+        landParcel: landParcelPDA,
+        treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
+        jurisdiction: jurisdictionPDA,
+        authority: provider.wallet.publicKey,
+        districtRules: findDistrictRulesPDA(district),
+        districtStats: findDistrictStatsPDA(district),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return landParcelPDA;
+  };
+
+  const verifyParcel = async (ulpinId: string) => {
+    await program.methods
+      .verifyLandParcel(ulpinId)
+      .accounts({
+        landParcel: findLandParcelPDA(ulpinId),
+        treasury: treasuryPDA,
+        authority: provider.wallet.publicKey,
+        owner: null,
+        accreditedFirm: null,
+        verificationState: findVerificationStatePDA(findLandParcelPDA(ulpinId)),
+        verifierRegistry: null,
+        districtStats: await districtStatsFor(ulpinId),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  };
+
+  // Issues a single token from a zero-decimal mint and revokes the mint
+  // authority, which is what the program accepts as a parcel NFT
+  const createNftMint = async (freezeAuthority: PublicKey | null = null) => {
+    const nftMint = await createMint(connection, provider.wallet.payer, provider.wallet.publicKey, freezeAuthority, 0);
+    const nftTokenAccount = await createAccount(connection, provider.wallet.payer, nftMint, provider.wallet.publicKey);
+    await mintTo(connection, provider.wallet.payer, nftMint, nftTokenAccount, provider.wallet.payer, 1);
+    await setAuthority(
+      connection,
+      provider.wallet.payer,
+      nftMint,
+      provider.wallet.publicKey,
+      AuthorityType.MintTokens,
+      null
+    );
+    return { nftMint, nftTokenAccount };
+  };
+
+  const mintParcel = async (
+    ulpinId: string,
+    owner: PublicKey = provider.wallet.publicKey,
+    overrides: Record<string, PublicKey | null> = {},
+    exemptionBps = 0
+  ) => {
+    const nftMint = overrides.nftMint ?? (await createNftMint()).nftMint;
+    await program.methods
+      .mintLandNft(ulpinId, "https://ipfs.io/ipfs/QmTest123456789", exemptionBps)
+      .accounts({
+        landParcel: findLandParcelPDA(ulpinId),
+        treasury: treasuryPDA,
+        nftMint,
+        userTokenAccount: userTokenAccount,
+        treasuryTokenAccount: treasuryTokenAccount,
+        ownerMintRecord: findOwnerMintRecordPDA(owner),
+        districtTreasury: await districtTreasuryFor(ulpinId),
+        landUseRevenue: landUseRevenuePDA,
+        feeConfig: feeConfigPDA,
+        exemptionAuthority: null,
+        user: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        districtStats: await districtStatsFor(ulpinId),
+        systemProgram: SystemProgram.programId,
+        ...overrides,
+      })
+      .rpc();
+  };
+
+  const [freezeAuthorityPDA, freezeAuthorityBump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("freeze_authority")],
+    program.programId
+  );
+
+  const ensureFreezeAuthority = async () => {
+    if (await program.account.freezeAuthorityPda.fetchNullable(freezeAuthorityPDA)) {
+      return;
+    }
+    await program.methods
+      .initializeFreezeAuthority(freezeAuthorityBump)
+      .accounts({
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  };
+
+  // Registers, verifies and mints a parcel backed by a supply-1 mint whose
+  // freeze authority is the program's freeze authority PDA.
+  const prepareFreezableParcel = async (ulpinId: string) => {
+    await ensureFreezeAuthority();
+    await registerParcel(ulpinId, provider.wallet.publicKey);
+    await verifyParcel(ulpinId);
+
+    const { nftMint, nftTokenAccount } = await createNftMint(freezeAuthorityPDA);
+    await mintParcel(ulpinId, provider.wallet.publicKey, { nftMint });
+    return { nftMint, nftTokenAccount };
+  };
+
+  const findOwnershipHistoryPDA = (ulpinId: string, page = 0): PublicKey => {
+    const pageBytes = Buffer.alloc(4);
+    pageBytes.writeUInt32LE(page);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("ownership_history"), findLandParcelPDA(ulpinId).toBuffer(), pageBytes],
+      program.programId
+    )[0];
+  };
+
+  const transferParcel = (ulpinId: string, newOwner: PublicKey, historyPage = 0) =>
+    program.methods
+      .updateLandOwnership(ulpinId, newOwner)
+      .accounts({
+        landParcel: findLandParcelPDA(ulpinId),
+        treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
+        authority: provider.wallet.publicKey,
+        villageConfig: findVillageConfigPDA(),
+        ownershipHistory: findOwnershipHistoryPDA(ulpinId, historyPage),
+        protectionAuthority: null,
+        panchayat: null,
+        karta: null,
+        systemProgram: SystemProgram.programId,
+      });
+
   before(async () => {
     // Derive treasury PDA
     [treasuryPDA, treasuryBump] = await PublicKey.findProgramAddress(
@@ -41,13 +301,23 @@ describe("ulpin-treasury", () => {
       .initializeTreasury(treasuryBump)
       .accounts({
         treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
         authority: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
+    await program.methods
+      .setFeeMint(mint)
+      .accounts({
+        treasury: treasuryPDA,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
     const treasury = await program.account.treasury.fetch(treasuryPDA);
     expect(treasury.authority.toString()).to.equal(provider.wallet.publicKey.toString());
+    expect(treasury.feeMint.toString()).to.equal(mint.toString());
     expect(treasury.totalFeesCollected.toNumber()).to.equal(0);
     expect(treasury.landParcelCount.toNumber()).to.equal(0);
     expect(treasury.isActive).to.be.true;
@@ -57,7 +327,7 @@ describe("ulpin-treasury", () => {
     const ulpinId = "GJ12345678901234567890";
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
@@ -68,12 +338,22 @@ describe("ulpin-treasury", () => {
         "Ahmedabad",
         "City",
         "Village1",
-        provider.wallet.publicKey
+        provider.wallet.publicKey,
+        documentHash,
+        bbox.minLat,
+        bbox.minLon,
+        bbox.maxLat,
+        bbox.maxLon,
+        LandUse.agricultural
       )
       .accounts({
         landParcel: landParcelPDA,
         treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
+        jurisdiction: jurisdictionPDA,
         authority: provider.wallet.publicKey,
+        districtRules: findDistrictRulesPDA("Ahmedabad"),
+        districtStats: findDistrictStatsPDA("Ahmedabad"),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -94,7 +374,7 @@ describe("ulpin-treasury", () => {
     const ulpinId = "GJ12345678901234567890";
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
@@ -102,7 +382,14 @@ describe("ulpin-treasury", () => {
       .verifyLandParcel(ulpinId)
       .accounts({
         landParcel: landParcelPDA,
+        treasury: treasuryPDA,
         authority: provider.wallet.publicKey,
+        owner: null,
+        accreditedFirm: null,
+        verificationState: findVerificationStatePDA(landParcelPDA),
+        verifierRegistry: null,
+        districtStats: findDistrictStatsPDA("Ahmedabad"),
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
@@ -115,23 +402,30 @@ describe("ulpin-treasury", () => {
     const metadataUri = "https://ipfs.io/ipfs/QmTest123456789";
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
     const treasuryBefore = await program.account.treasury.fetch(treasuryPDA);
     const initialFees = treasuryBefore.totalFeesCollected.toNumber();
+    const { nftMint } = await createNftMint();
 
     await program.methods
-      .mintLandNft(ulpinId, metadataUri)
+      .mintLandNft(ulpinId, metadataUri, 0)
       .accounts({
         landParcel: landParcelPDA,
         treasury: treasuryPDA,
-        nftMint: mint,
+        nftMint,
         userTokenAccount: userTokenAccount,
         treasuryTokenAccount: treasuryTokenAccount,
+        ownerMintRecord: findOwnerMintRecordPDA(provider.wallet.publicKey),
+        districtTreasury: findDistrictTreasuryPDA("Ahmedabad"),
+        landUseRevenue: landUseRevenuePDA,
+        feeConfig: feeConfigPDA,
+        exemptionAuthority: null,
         user: provider.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        districtStats: findDistrictStatsPDA("Ahmedabad"),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -149,7 +443,7 @@ describe("ulpin-treasury", () => {
     const newOwner = anchor.web3.Keypair.generate().publicKey;
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
@@ -157,7 +451,15 @@ describe("ulpin-treasury", () => {
       .updateLandOwnership(ulpinId, newOwner)
       .accounts({
         landParcel: landParcelPDA,
+        treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
         authority: provider.wallet.publicKey,
+        villageConfig: findVillageConfigPDA(),
+        ownershipHistory: findOwnershipHistoryPDA(ulpinId),
+        protectionAuthority: null,
+        panchayat: null,
+        karta: null,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
@@ -169,7 +471,7 @@ describe("ulpin-treasury", () => {
     const ulpinId = "GJ12345678901234567890"; // Same as before
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
@@ -181,12 +483,22 @@ describe("ulpin-treasury", () => {
           "Surat",
           "District",
           "Village2",
-          provider.wallet.publicKey
+          provider.wallet.publicKey,
+          documentHash,
+          bbox.minLat,
+          bbox.minLon,
+          bbox.maxLat,
+          bbox.maxLon,
+          LandUse.agricultural
         )
         .accounts({
           landParcel: landParcelPDA,
           treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
           authority: provider.wallet.publicKey,
+          districtRules: findDistrictRulesPDA("Surat"),
+          districtStats: findDistrictStatsPDA("Surat"),
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -200,7 +512,7 @@ describe("ulpin-treasury", () => {
     const ulpinId = "GJ98765432109876543210";
     
     const [landParcelPDA] = await PublicKey.findProgramAddress(
-      [Buffer.from("land_parcel"), Buffer.from(ulpinId)],
+      [Buffer.from("land_parcel"), Buffer.from(keccak_256(ulpinId))],
       program.programId
     );
 
@@ -212,27 +524,45 @@ describe("ulpin-treasury", () => {
         "Vadodara",
         "District",
         "Village3",
-        provider.wallet.publicKey
+        provider.wallet.publicKey,
+        documentHash,
+        bbox.minLat,
+        bbox.minLon,
+        bbox.maxLat,
+        bbox.maxLon,
+        LandUse.agricultural
       )
       .accounts({
         landParcel: landParcelPDA,
         treasury: treasuryPDA,
+        sanctionsList: sanctionsListPDA,
+        jurisdiction: jurisdictionPDA,
         authority: provider.wallet.publicKey,
+        districtRules: findDistrictRulesPDA("Vadodara"),
+        districtStats: findDistrictStatsPDA("Vadodara"),
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
+    const { nftMint } = await createNftMint();
+
     try {
       await program.methods
-        .mintLandNft(ulpinId, "https://ipfs.io/ipfs/QmTest987654321")
+        .mintLandNft(ulpinId, "https://ipfs.io/ipfs/QmTest987654321", 0)
         .accounts({
           landParcel: landParcelPDA,
           treasury: treasuryPDA,
-          nftMint: mint,
+          nftMint,
           userTokenAccount: userTokenAccount,
           treasuryTokenAccount: treasuryTokenAccount,
+          ownerMintRecord: findOwnerMintRecordPDA(provider.wallet.publicKey),
+          districtTreasury: findDistrictTreasuryPDA("Vadodara"),
+          landUseRevenue: landUseRevenuePDA,
+          feeConfig: feeConfigPDA,
+          exemptionAuthority: null,
           user: provider.wallet.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
+          districtStats: findDistrictStatsPDA("Vadodara"),
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -241,4 +571,4578 @@ describe("ulpin-treasury", () => {
       expect(error.message).to.include("LandNotVerified");
     }
   });
+
+  describe("owner co-signed verification", () => {
+    const owner = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await program.methods
+        .setOwnerAckRequired(true)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setOwnerAckRequired(false)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    it("Verifies when the owner co-signs", async () => {
+      const ulpinId = "GJACK0000000000000001";
+      const landParcelPDA = await registerParcel(ulpinId, owner.publicKey);
+
+      const listener = program.addEventListener("LandParcelVerified", (event) => {
+        expect(event.verifier.toString()).to.equal(provider.wallet.publicKey.toString());
+        expect(event.ownerAck.toString()).to.equal(owner.publicKey.toString());
+      });
+
+      await program.methods
+        .verifyLandParcel(ulpinId)
+        .accounts({
+          landParcel: landParcelPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          owner: owner.publicKey,
+          accreditedFirm: null,
+          verificationState: findVerificationStatePDA(landParcelPDA),
+          verifierRegistry: null,
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+      await program.removeEventListener(listener);
+
+      const landParcel = await program.account.landParcel.fetch(landParcelPDA);
+      expect(landParcel.isVerified).to.be.true;
+    });
+
+    it("Rejects verification without the owner's signature", async () => {
+      const ulpinId = "GJACK0000000000000002";
+      const landParcelPDA = await registerParcel(ulpinId, owner.publicKey);
+
+      try {
+        await program.methods
+          .verifyLandParcel(ulpinId)
+          .accounts({
+            landParcel: landParcelPDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+            owner: null,
+            accreditedFirm: null,
+            verificationState: findVerificationStatePDA(landParcelPDA),
+            verifierRegistry: null,
+            districtStats: findDistrictStatsPDA("Ahmedabad"),
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed without owner co-signature");
+      } catch (error) {
+        expect(error.message).to.include("OwnerAckRequired");
+      }
+    });
+  });
+
+  describe("mint settlement window", () => {
+    const ulpinId = "GJSETTLE000000000001";
+
+    before(async () => {
+      await program.methods
+        .setMintSettlementSeconds(3)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    });
+
+    after(async () => {
+      await program.methods
+        .setMintSettlementSeconds(null)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    it("Rejects a transfer before the mint has settled", async () => {
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have failed before settlement");
+      } catch (error) {
+        expect(error.message).to.include("MintNotSettled");
+      }
+    });
+
+    it("Allows the transfer once the settlement window has elapsed", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await transferParcel(ulpinId, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("heritage protection", () => {
+    const protectedUlpin = "GJHERITAGE0000000001";
+    const normalUlpin = "GJHERITAGE0000000002";
+    const protectionAuthority = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      for (const ulpinId of [protectedUlpin, normalUlpin]) {
+        await registerParcel(ulpinId, provider.wallet.publicKey);
+        await verifyParcel(ulpinId);
+        await mintParcel(ulpinId);
+      }
+
+      await program.methods
+        .setParcelProtection(protectedUlpin, true, protectionAuthority.publicKey)
+        .accounts({
+          landParcel: findLandParcelPDA(protectedUlpin),
+          treasury: treasuryPDA,
+          heritageAuthority: provider.wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Rejects transferring a protected parcel without the protection authority", async () => {
+      try {
+        await transferParcel(protectedUlpin, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have required the protection authority");
+      } catch (error) {
+        expect(error.message).to.include("ProtectionAuthorityRequired");
+      }
+    });
+
+    it("Transfers a protected parcel co-signed by the protection authority", async () => {
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await program.methods
+        .updateLandOwnership(protectedUlpin, newOwner)
+        .accounts({
+          landParcel: findLandParcelPDA(protectedUlpin),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          authority: provider.wallet.publicKey,
+          villageConfig: findVillageConfigPDA(),
+          ownershipHistory: findOwnershipHistoryPDA(protectedUlpin),
+          protectionAuthority: protectionAuthority.publicKey,
+          panchayat: null,
+          karta: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([protectionAuthority])
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(protectedUlpin));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Transfers a normal parcel without any extra signature", async () => {
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await transferParcel(normalUlpin, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(normalUlpin));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("historical registration backfill", () => {
+    it("Backdates the registration of an unverified parcel", async () => {
+      const ulpinId = "GJBACKFILL0000000001";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+      const historicalTimestamp = new anchor.BN(946684800); // 2000-01-01
+
+      await program.methods
+        .setHistoricalRegistration(ulpinId, historicalTimestamp)
+        .accounts({
+          landParcel: landParcelPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(landParcelPDA);
+      expect(landParcel.registrationTimestamp.toNumber()).to.equal(historicalTimestamp.toNumber());
+    });
+
+    it("Rejects a historical timestamp in the future", async () => {
+      const ulpinId = "GJBACKFILL0000000002";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+      const futureTimestamp = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+
+      try {
+        await program.methods
+          .setHistoricalRegistration(ulpinId, futureTimestamp)
+          .accounts({
+            landParcel: landParcelPDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have rejected a future timestamp");
+      } catch (error) {
+        expect(error.message).to.include("FutureRegistrationTimestamp");
+      }
+    });
+  });
+
+  describe("batched fee withdrawal", () => {
+    const landDept = anchor.web3.Keypair.generate();
+    const revenueDept = anchor.web3.Keypair.generate();
+    let landDeptTokenAccount: PublicKey;
+    let revenueDeptTokenAccount: PublicKey;
+
+    before(async () => {
+      landDeptTokenAccount = await createAccount(connection, provider.wallet.payer, mint, landDept.publicKey);
+      revenueDeptTokenAccount = await createAccount(connection, provider.wallet.payer, mint, revenueDept.publicKey);
+    });
+
+    it("Disburses fees to several beneficiaries", async () => {
+      await program.methods
+        .withdrawFeesBatch([
+          { beneficiary: landDept.publicKey, amount: new anchor.BN(1000) },
+          { beneficiary: revenueDept.publicKey, amount: new anchor.BN(2000) },
+        ])
+        .accounts({
+          treasury: treasuryPDA,
+          treasuryTokenAccount: treasuryTokenAccount,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: landDeptTokenAccount, isWritable: true, isSigner: false },
+          { pubkey: revenueDeptTokenAccount, isWritable: true, isSigner: false },
+        ])
+        .rpc();
+
+      expect(Number((await getAccount(connection, landDeptTokenAccount)).amount)).to.equal(1000);
+      expect(Number((await getAccount(connection, revenueDeptTokenAccount)).amount)).to.equal(2000);
+    });
+
+    it("Rejects a batch exceeding the treasury balance", async () => {
+      const balance = (await getAccount(connection, treasuryTokenAccount)).amount;
+
+      try {
+        await program.methods
+          .withdrawFeesBatch([
+            { beneficiary: landDept.publicKey, amount: new anchor.BN(balance.toString()) },
+            { beneficiary: revenueDept.publicKey, amount: new anchor.BN(1) },
+          ])
+          .accounts({
+            treasury: treasuryPDA,
+            treasuryTokenAccount: treasuryTokenAccount,
+            authority: provider.wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: landDeptTokenAccount, isWritable: true, isSigner: false },
+            { pubkey: revenueDeptTokenAccount, isWritable: true, isSigner: false },
+          ])
+          .rpc();
+        expect.fail("Should have rejected an overdrawn batch");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientTreasuryBalance");
+      }
+    });
+  });
+
+  describe("duplicate parcel deduplication", () => {
+    it("Merges a duplicate record into the canonical parcel", async () => {
+      const canonicalUlpin = "GJDEDUPE000000000001";
+      const duplicateUlpin = "GJDEDUPE000000000002";
+      const canonicalPDA = await registerParcel(canonicalUlpin, provider.wallet.publicKey);
+      const duplicatePDA = await registerParcel(duplicateUlpin, provider.wallet.publicKey);
+      await verifyParcel(duplicateUlpin);
+      const { nftMint } = await createNftMint();
+      await mintParcel(duplicateUlpin, provider.wallet.publicKey, { nftMint });
+      const countBefore = (await program.account.treasury.fetch(treasuryPDA)).landParcelCount.toNumber();
+
+      await program.methods
+        .dedupeParcels(canonicalUlpin, duplicateUlpin)
+        .accounts({
+          canonicalParcel: canonicalPDA,
+          duplicateParcel: duplicatePDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const canonical = await program.account.landParcel.fetch(canonicalPDA);
+      expect(canonical.isVerified).to.be.true;
+      expect(canonical.verifiedAt).to.not.be.null;
+      expect(canonical.verifiedBy.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(canonical.nftMinted).to.be.true;
+      expect(canonical.nftMint.toString()).to.equal(nftMint.toString());
+      expect(canonical.metadataUri).to.equal("https://ipfs.io/ipfs/QmTest123456789");
+      expect(await program.account.landParcel.fetchNullable(duplicatePDA)).to.be.null;
+      const treasury = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasury.landParcelCount.toNumber()).to.equal(countBefore - 1);
+    });
+
+    it("Rejects deduplicating parcels with different owners", async () => {
+      const canonicalUlpin = "GJDEDUPE000000000003";
+      const duplicateUlpin = "GJDEDUPE000000000004";
+      const canonicalPDA = await registerParcel(canonicalUlpin, provider.wallet.publicKey);
+      const duplicatePDA = await registerParcel(duplicateUlpin, anchor.web3.Keypair.generate().publicKey);
+
+      try {
+        await program.methods
+          .dedupeParcels(canonicalUlpin, duplicateUlpin)
+          .accounts({
+            canonicalParcel: canonicalPDA,
+            duplicateParcel: duplicatePDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have rejected mismatched parcels");
+      } catch (error) {
+        expect(error.message).to.include("ParcelsNotDuplicates");
+      }
+    });
+
+    it("Rejects closing a duplicate that is under a hold", async () => {
+      const canonicalUlpin = "GJDEDUPE000000000005";
+      const duplicateUlpin = "GJDEDUPE000000000006";
+      const canonicalPDA = await registerParcel(canonicalUlpin, provider.wallet.publicKey);
+      const duplicatePDA = await registerParcel(duplicateUlpin, provider.wallet.publicKey);
+      await program.methods
+        .quarantineParcel(duplicateUlpin, new anchor.BN(3600), "Suspected duplicate entry")
+        .accounts({ landParcel: duplicatePDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .dedupeParcels(canonicalUlpin, duplicateUlpin)
+          .accounts({
+            canonicalParcel: canonicalPDA,
+            duplicateParcel: duplicatePDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have kept the held duplicate");
+      } catch (error) {
+        expect(error.message).to.include("ParcelQuarantined");
+      }
+    });
+  });
+
+  describe("seasonal transfer windows", () => {
+    const ulpinId = "GJWINDOW000000000001";
+    const landParcelPDA = findLandParcelPDA(ulpinId);
+
+    const setWindow = (startTs: number, endTs: number) =>
+      program.methods
+        .setTransferWindow(ulpinId, new anchor.BN(startTs), new anchor.BN(endTs))
+        .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    before(async () => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    });
+
+    it("Rejects a transfer outside the allowed window", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await setWindow(now + 86400, now + 2 * 86400);
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have rejected a transfer outside the window");
+      } catch (error) {
+        expect(error.message).to.include("OutsideTransferWindow");
+      }
+    });
+
+    it("Allows a transfer inside the allowed window", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await setWindow(now - 86400, now + 86400);
+
+      await transferParcel(ulpinId, provider.wallet.publicKey).rpc();
+    });
+
+    it("Removes the restriction when the window is cleared", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await setWindow(now + 86400, now + 2 * 86400);
+      await program.methods
+        .clearTransferWindow(ulpinId)
+        .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+      await transferParcel(ulpinId, provider.wallet.publicKey).rpc();
+      const landParcel = await program.account.landParcel.fetch(landParcelPDA);
+      expect(landParcel.transferWindow).to.be.null;
+    });
+  });
+
+  describe("per-owner mint limits", () => {
+    const citizen = anchor.web3.Keypair.generate().publicKey;
+    const government = anchor.web3.Keypair.generate().publicKey;
+
+    const prepareParcel = async (ulpinId: string, owner: PublicKey) => {
+      await registerParcel(ulpinId, owner);
+      await verifyParcel(ulpinId);
+    };
+
+    before(async () => {
+      await program.methods
+        .setMaxNftsPerOwner(2)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setOwnerMintExemption(government, true)
+        .accounts({
+          ownerMintRecord: findOwnerMintRecordPDA(government),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setMaxNftsPerOwner(null)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    it("Mints up to the limit and rejects the next NFT for an owner", async () => {
+      for (const ulpinId of ["GJLIMIT0000000000001", "GJLIMIT0000000000002", "GJLIMIT0000000000003"]) {
+        await prepareParcel(ulpinId, citizen);
+      }
+      await mintParcel("GJLIMIT0000000000001", citizen);
+      await mintParcel("GJLIMIT0000000000002", citizen);
+
+      try {
+        await mintParcel("GJLIMIT0000000000003", citizen);
+        expect.fail("Should have hit the per-owner mint limit");
+      } catch (error) {
+        expect(error.message).to.include("OwnerMintLimitReached");
+      }
+
+      const record = await program.account.ownerMintRecord.fetch(findOwnerMintRecordPDA(citizen));
+      expect(record.mintedCount).to.equal(2);
+    });
+
+    it("Does not limit an exempt government owner", async () => {
+      for (const ulpinId of ["GJLIMIT0000000000004", "GJLIMIT0000000000005", "GJLIMIT0000000000006"]) {
+        await prepareParcel(ulpinId, government);
+        await mintParcel(ulpinId, government);
+      }
+
+      const record = await program.account.ownerMintRecord.fetch(findOwnerMintRecordPDA(government));
+      expect(record.mintedCount).to.equal(3);
+    });
+  });
+
+  describe("survey boundary polygons", () => {
+    const findBoundaryPDA = (landParcelPDA: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("boundary"), landParcelPDA.toBuffer()],
+        program.programId
+      )[0];
+
+    const addPoints = async (landParcelPDA: PublicKey, points: [number, number][]) => {
+      for (const [lat, lon] of points) {
+        await program.methods
+          .addBoundaryPoint(lat, lon)
+          .accounts({
+            parcelBoundary: findBoundaryPDA(landParcelPDA),
+            landParcel: landParcelPDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+    };
+
+    const finalize = (ulpinId: string, landParcelPDA: PublicKey) =>
+      program.methods
+        .finalizeBoundary(ulpinId)
+        .accounts({
+          parcelBoundary: findBoundaryPDA(landParcelPDA),
+          landParcel: landParcelPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Finalizes a triangle boundary", async () => {
+      const ulpinId = "GJBOUNDARY0000000001";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+      await addPoints(landParcelPDA, [
+        [23022500, 72571300],
+        [23022600, 72571300],
+        [23022600, 72571400],
+      ]);
+
+      await finalize(ulpinId, landParcelPDA);
+
+      const boundary = await program.account.parcelBoundary.fetch(findBoundaryPDA(landParcelPDA));
+      expect(boundary.vertices.length).to.equal(3);
+      expect(boundary.finalized).to.be.true;
+    });
+
+    it("Rejects finalizing a two-point boundary", async () => {
+      const ulpinId = "GJBOUNDARY0000000002";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+      await addPoints(landParcelPDA, [
+        [23022500, 72571300],
+        [23022600, 72571300],
+      ]);
+
+      try {
+        await finalize(ulpinId, landParcelPDA);
+        expect.fail("Should have required at least three vertices");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientBoundaryPoints");
+      }
+    });
+  });
+
+  describe("re-verification after transfer", () => {
+    const setReverify = (enabled: boolean) =>
+      program.methods
+        .setReverifyOnTransfer(enabled)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    };
+
+    it("Resets verification on transfer when enabled", async () => {
+      const ulpinId = "GJREVERIFY0000000001";
+      await prepareMintedParcel(ulpinId);
+      await setReverify(true);
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+      } finally {
+        await setReverify(false);
+      }
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.isVerified).to.be.false;
+      expect(landParcel.verifiedAt).to.be.null;
+    });
+
+    it("Keeps verification on transfer when disabled", async () => {
+      const ulpinId = "GJREVERIFY0000000002";
+      await prepareMintedParcel(ulpinId);
+
+      await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.isVerified).to.be.true;
+      expect(landParcel.verifiedAt).to.not.be.null;
+    });
+  });
+
+  describe("district fee segregation", () => {
+    const districts = ["Surat", "Rajkot"];
+    const districtTokenAccounts: Record<string, PublicKey> = {};
+
+    before(async () => {
+      for (const district of districts) {
+        districtTokenAccounts[district] = await createAccount(
+          connection,
+          provider.wallet.payer,
+          mint,
+          treasuryPDA,
+          anchor.web3.Keypair.generate()
+        );
+        await program.methods
+          .setDistrictTreasury(district, districtTokenAccounts[district])
+          .accounts({
+            districtTreasury: findDistrictTreasuryPDA(district),
+            treasury: treasuryPDA,
+            districtTokenAccount: districtTokenAccounts[district],
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+    });
+
+    it("Routes mint fees to each parcel's district treasury", async () => {
+      const expectedFee = 100000 + 1000 * 10;
+
+      for (const [index, district] of districts.entries()) {
+        const ulpinId = `GJDISTRICT000000000${index}`;
+        await registerParcel(ulpinId, provider.wallet.publicKey, { district });
+        await verifyParcel(ulpinId);
+        await mintParcel(ulpinId, provider.wallet.publicKey, {
+          treasuryTokenAccount: districtTokenAccounts[district],
+          districtTreasury: findDistrictTreasuryPDA(district),
+        });
+
+        const account = await getAccount(connection, districtTokenAccounts[district]);
+        expect(Number(account.amount)).to.equal(expectedFee);
+        const districtTreasury = await program.account.districtTreasury.fetch(findDistrictTreasuryPDA(district));
+        expect(districtTreasury.totalCollected.toNumber()).to.equal(expectedFee);
+      }
+    });
+
+    it("Rejects a district token account the treasury cannot withdraw from", async () => {
+      const payerOwned = await createAccount(
+        connection,
+        provider.wallet.payer,
+        mint,
+        provider.wallet.publicKey,
+        anchor.web3.Keypair.generate()
+      );
+
+      try {
+        await program.methods
+          .setDistrictTreasury("Bhavnagar", payerOwned)
+          .accounts({
+            districtTreasury: findDistrictTreasuryPDA("Bhavnagar"),
+            treasury: treasuryPDA,
+            districtTokenAccount: payerOwned,
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("A payer-owned district token account should be rejected");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTreasuryTokenAccount");
+      }
+    });
+
+    it("Rejects fees paid into an account the treasury does not own", async () => {
+      const ulpinId = "GJDISTRICT0000000009";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      const payerOwned = await createAccount(
+        connection,
+        provider.wallet.payer,
+        mint,
+        provider.wallet.publicKey,
+        anchor.web3.Keypair.generate()
+      );
+
+      try {
+        await mintParcel(ulpinId, provider.wallet.publicKey, { treasuryTokenAccount: payerOwned });
+        expect.fail("Minting into a payer-owned fee account should fail");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTreasuryTokenAccount");
+      }
+    });
+  });
+
+  describe("occupancy status", () => {
+    const owner = anchor.web3.Keypair.generate();
+    const ulpinId = "GJOCCUPANCY000000001";
+    const landParcelPDA = findLandParcelPDA(ulpinId);
+
+    before(async () => {
+      await registerParcel(ulpinId, owner.publicKey);
+    });
+
+    it("Lets the owner record a tenant in possession", async () => {
+      const tenant = anchor.web3.Keypair.generate().publicKey;
+      await program.methods
+        .setOccupancy(ulpinId, { tenanted: {} }, tenant)
+        .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, signer: owner.publicKey })
+        .signers([owner])
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(landParcelPDA);
+      expect(landParcel.occupancyStatus).to.deep.equal({ tenanted: {} });
+      expect(landParcel.possessor.toString()).to.equal(tenant.toString());
+    });
+
+    it("Lets the treasury authority mark the parcel disputed", async () => {
+      await program.methods
+        .setOccupancy(ulpinId, { disputed: {} }, null)
+        .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, signer: provider.wallet.publicKey })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(landParcelPDA);
+      expect(landParcel.occupancyStatus).to.deep.equal({ disputed: {} });
+      expect(landParcel.possessor).to.be.null;
+    });
+
+    it("Rejects occupancy updates from unrelated keys", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .setOccupancy(ulpinId, { vacant: {} }, null)
+          .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, signer: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have rejected an unauthorized signer");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOccupancyUpdate");
+      }
+    });
+  });
+
+  describe("parcel quarantine", () => {
+    const mintedUlpin = "GJQUARANTINE0000001";
+    const unmintedUlpin = "GJQUARANTINE0000002";
+
+    const quarantine = (ulpinId: string, durationSeconds: number) =>
+      program.methods
+        .quarantineParcel(ulpinId, new anchor.BN(durationSeconds), "Anomalous transfer pattern")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    before(async () => {
+      await registerParcel(mintedUlpin, provider.wallet.publicKey);
+      await verifyParcel(mintedUlpin);
+      await mintParcel(mintedUlpin);
+      await registerParcel(unmintedUlpin, provider.wallet.publicKey);
+      await verifyParcel(unmintedUlpin);
+
+      await quarantine(mintedUlpin, 3);
+      await quarantine(unmintedUlpin, 3);
+    });
+
+    it("Blocks transfers and mints while quarantined", async () => {
+      try {
+        await transferParcel(mintedUlpin, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have blocked the transfer");
+      } catch (error) {
+        expect(error.message).to.include("ParcelQuarantined");
+      }
+
+      try {
+        await mintParcel(unmintedUlpin);
+        expect.fail("Should have blocked the mint");
+      } catch (error) {
+        expect(error.message).to.include("ParcelQuarantined");
+      }
+    });
+
+    it("Resumes operations once the quarantine lapses", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      await transferParcel(mintedUlpin, provider.wallet.publicKey).rpc();
+      await mintParcel(unmintedUlpin);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(unmintedUlpin));
+      expect(landParcel.nftMinted).to.be.true;
+    });
+  });
+
+  describe("sanctions screening", () => {
+    const sanctioned = anchor.web3.Keypair.generate().publicKey;
+    const ulpinId = "GJSANCTIONS00000001";
+
+    before(async () => {
+      await program.methods
+        .addSanctionedParty(sanctioned)
+        .accounts({ sanctionsList: sanctionsListPDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    });
+
+    it("Rejects registering a parcel to a sanctioned owner", async () => {
+      try {
+        await registerParcel("GJSANCTIONS00000002", sanctioned);
+        expect.fail("Should have rejected a sanctioned owner");
+      } catch (error) {
+        expect(error.message).to.include("SanctionedParty");
+      }
+    });
+
+    it("Rejects transferring a parcel to a sanctioned recipient", async () => {
+      try {
+        await transferParcel(ulpinId, sanctioned).rpc();
+        expect.fail("Should have rejected a sanctioned recipient");
+      } catch (error) {
+        expect(error.message).to.include("SanctionedParty");
+      }
+    });
+
+    it("Allows transferring to a clean recipient", async () => {
+      const recipient = anchor.web3.Keypair.generate().publicKey;
+      await transferParcel(ulpinId, recipient).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(recipient.toString());
+    });
+  });
+
+  describe("consolidated treasury holdings", () => {
+    it("Emits the sum of the provided treasury token accounts", async () => {
+      const holdingAccounts: PublicKey[] = [];
+      for (const amount of [5000, 7000]) {
+        const account = await createAccount(
+          connection,
+          provider.wallet.payer,
+          mint,
+          treasuryPDA,
+          anchor.web3.Keypair.generate()
+        );
+        await mintTo(connection, provider.wallet.payer, mint, account, provider.wallet.payer, amount);
+        holdingAccounts.push(account);
+      }
+
+      let emitted: any = null;
+      const listener = program.addEventListener("ConsolidatedHoldings", (event) => {
+        emitted = event;
+      });
+
+      await program.methods
+        .emitConsolidatedHoldings()
+        .accounts({ treasury: treasuryPDA })
+        .remainingAccounts(
+          holdingAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+        )
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(emitted).to.not.be.null;
+      expect(emitted.total.toNumber()).to.equal(12000);
+      expect(emitted.holdings.map((holding) => holding.amount.toNumber())).to.deep.equal([5000, 7000]);
+    });
+  });
+
+  describe("court case references on freezes", () => {
+    const ulpinId = "GJCASEREF0000000001";
+    const caseReference = Buffer.alloc(32);
+    caseReference.write("GJHC/SCA/1234/2025");
+    let nftMint: PublicKey;
+    let nftTokenAccount: PublicKey;
+
+    before(async () => {
+      ({ nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId));
+    });
+
+    it("Stores the case reference for the duration of the freeze", async () => {
+      await program.methods
+        .freezeLandNft(new anchor.BN(2), [...caseReference], 1, "Stay order in pending civil suit")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(Buffer.from(landParcel.caseReference)).to.deep.equal(caseReference);
+    });
+
+    it("Clears the case reference on thaw", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await program.methods
+        .thawLandNft()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(Buffer.from(landParcel.caseReference)).to.deep.equal(Buffer.alloc(32));
+    });
+  });
+
+  describe("panchayat transfer approval", () => {
+    const panchayat = anchor.web3.Keypair.generate();
+    const approvalUlpin = "GJPANCHAYAT00000001";
+    const normalUlpin = "GJPANCHAYAT00000002";
+
+    before(async () => {
+      await program.methods
+        .setVillageConfig("Ahmedabad", "City", "Village7", true, panchayat.publicKey)
+        .accounts({
+          villageConfig: findVillageConfigPDA("Ahmedabad", "City", "Village7"),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await registerParcel(approvalUlpin, provider.wallet.publicKey, { village: "Village7" });
+      await verifyParcel(approvalUlpin);
+      await mintParcel(approvalUlpin);
+      await registerParcel(normalUlpin, provider.wallet.publicKey);
+      await verifyParcel(normalUlpin);
+      await mintParcel(normalUlpin);
+    });
+
+    const transferInVillage7 = (newOwner: PublicKey, withPanchayat: boolean) => {
+      const builder = program.methods
+        .updateLandOwnership(approvalUlpin, newOwner)
+        .accounts({
+          landParcel: findLandParcelPDA(approvalUlpin),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          villageConfig: findVillageConfigPDA("Ahmedabad", "City", "Village7"),
+          ownershipHistory: findOwnershipHistoryPDA(approvalUlpin),
+          authority: provider.wallet.publicKey,
+          protectionAuthority: null,
+          panchayat: withPanchayat ? panchayat.publicKey : null,
+          karta: null,
+          systemProgram: SystemProgram.programId,
+        });
+      return withPanchayat ? builder.signers([panchayat]).rpc() : builder.rpc();
+    };
+
+    it("Rejects a transfer without the panchayat's signature", async () => {
+      try {
+        await transferInVillage7(anchor.web3.Keypair.generate().publicKey, false);
+        expect.fail("Should have required panchayat approval");
+      } catch (error) {
+        expect(error.message).to.include("PanchayatApprovalRequired");
+      }
+    });
+
+    it("Transfers with the panchayat's co-signature", async () => {
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await transferInVillage7(newOwner, true);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(approvalUlpin));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Transfers in a village without a panchayat requirement", async () => {
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await transferParcel(normalUlpin, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(normalUlpin));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("automatic sweep threshold", () => {
+    const mintFee = 100000 + 1000 * 10;
+
+    const setThreshold = (threshold: anchor.BN | null) =>
+      program.methods
+        .setAutoSweepThreshold(threshold)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    const mintAndCollectSweepEvents = async (ulpinId: string) => {
+      const events: any[] = [];
+      const listener = program.addEventListener("TreasurySweepRecommended", (event) => {
+        events.push(event);
+      });
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      return events;
+    };
+
+    after(async () => {
+      await setThreshold(null);
+    });
+
+    it("Recommends a sweep when a mint pushes the balance over the threshold", async () => {
+      const balance = (await program.account.treasury.fetch(treasuryPDA)).currentBalance.toNumber();
+      await setThreshold(new anchor.BN(balance + mintFee - 1));
+
+      const events = await mintAndCollectSweepEvents("GJSWEEP000000000001");
+      expect(events).to.have.length(1);
+      expect(events[0].availableBalance.toNumber()).to.equal(balance + mintFee);
+    });
+
+    it("Does not recommend a sweep while the balance stays below the threshold", async () => {
+      const balance = (await program.account.treasury.fetch(treasuryPDA)).currentBalance.toNumber();
+      await setThreshold(new anchor.BN(balance + 10 * mintFee));
+
+      const events = await mintAndCollectSweepEvents("GJSWEEP000000000002");
+      expect(events).to.have.length(0);
+    });
+  });
+
+  describe("accredited surveying firms", () => {
+    const findAccreditedFirmPDA = (firm: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("accredited_firm"), firm.toBuffer()],
+        program.programId
+      )[0];
+
+    const accredit = (firm: PublicKey, accreditedUntil: number) =>
+      program.methods
+        .accreditFirm(firm, new anchor.BN(accreditedUntil))
+        .accounts({
+          accreditedFirm: findAccreditedFirmPDA(firm),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // The verifying firm pays for the parcel's approval record
+    const verifyAsFirm = async (ulpinId: string, firm: anchor.web3.Keypair) => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(firm.publicKey, LAMPORTS_PER_SOL)
+      );
+      return program.methods
+        .verifyLandParcel(ulpinId)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: firm.publicKey,
+          owner: null,
+          accreditedFirm: findAccreditedFirmPDA(firm.publicKey),
+          verificationState: findVerificationStatePDA(findLandParcelPDA(ulpinId)),
+          verifierRegistry: null,
+          districtStats: await districtStatsFor(ulpinId),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([firm])
+        .rpc();
+    };
+
+    it("Records an accredited firm as the verifier", async () => {
+      const firm = anchor.web3.Keypair.generate();
+      const ulpinId = "GJFIRM0000000000001";
+      await accredit(firm.publicKey, Math.floor(Date.now() / 1000) + 365 * 86400);
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      await verifyAsFirm(ulpinId, firm);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.isVerified).to.be.true;
+      expect(landParcel.verifiedBy.toString()).to.equal(firm.publicKey.toString());
+    });
+
+    it("Rejects verification by a firm whose accreditation expired", async () => {
+      const firm = anchor.web3.Keypair.generate();
+      const ulpinId = "GJFIRM0000000000002";
+      await accredit(firm.publicKey, Math.floor(Date.now() / 1000) - 86400);
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      try {
+        await verifyAsFirm(ulpinId, firm);
+        expect.fail("Should have rejected an expired accreditation");
+      } catch (error) {
+        expect(error.message).to.include("AccreditationExpired");
+      }
+    });
+
+    it("Rejects verification by a signer that is neither the treasury authority nor an accredited firm", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+      const ulpinId = "GJFIRM0000000000003";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(stranger.publicKey, LAMPORTS_PER_SOL)
+      );
+
+      try {
+        await program.methods
+          .verifyLandParcel(ulpinId)
+          .accounts({
+            landParcel: findLandParcelPDA(ulpinId),
+            treasury: treasuryPDA,
+            authority: stranger.publicKey,
+            owner: null,
+            accreditedFirm: null,
+            verificationState: findVerificationStatePDA(findLandParcelPDA(ulpinId)),
+            verifierRegistry: null,
+            districtStats: await districtStatsFor(ulpinId),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have rejected an unauthorized verifier");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedVerifier");
+      }
+    });
+  });
+
+  describe("owner-controlled token accounts", () => {
+    const setEnforcement = (enabled: boolean) =>
+      program.methods
+        .setEnforceOwnerTokenAccount(enabled)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    before(async () => {
+      await setEnforcement(true);
+    });
+
+    after(async () => {
+      await setEnforcement(false);
+    });
+
+    it("Mints when the token account belongs to the parcel owner", async () => {
+      const ulpinId = "GJTOKEN000000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+
+      await mintParcel(ulpinId);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMinted).to.be.true;
+    });
+
+    it("Rejects a token account owned by someone other than the parcel owner", async () => {
+      const citizen = anchor.web3.Keypair.generate().publicKey;
+      const ulpinId = "GJTOKEN000000000002";
+      await registerParcel(ulpinId, citizen);
+      await verifyParcel(ulpinId);
+
+      try {
+        await mintParcel(ulpinId, citizen);
+        expect.fail("Should have rejected a token account owned by a non-owner");
+      } catch (error) {
+        expect(error.message).to.include("TokenAccountOwnerMismatch");
+      }
+    });
+  });
+
+  describe("owner portfolio", () => {
+    const owner = anchor.web3.Keypair.generate().publicKey;
+    const ulpinIds = ["GJPORTFOLIO00000001", "GJPORTFOLIO00000002"];
+
+    before(async () => {
+      await registerParcel(ulpinIds[0], owner, { areaSqm: 1200 });
+      await registerParcel(ulpinIds[1], owner, { areaSqm: 800 });
+    });
+
+    const parcelAccounts = (ids: string[]) =>
+      ids.map((ulpinId) => ({
+        pubkey: findLandParcelPDA(ulpinId),
+        isWritable: false,
+        isSigner: false,
+      }));
+
+    it("Emits a summary per parcel and the portfolio aggregate", async () => {
+      const parcelSummaries: any[] = [];
+      let portfolio: any = null;
+      const parcelListener = program.addEventListener("ParcelSummary", (event) => {
+        parcelSummaries.push(event);
+      });
+      const portfolioListener = program.addEventListener("PortfolioSummary", (event) => {
+        portfolio = event;
+      });
+
+      await program.methods
+        .emitOwnerPortfolio(owner, ulpinIds)
+        .accounts({})
+        .remainingAccounts(parcelAccounts(ulpinIds))
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(parcelListener);
+      await program.removeEventListener(portfolioListener);
+
+      expect(parcelSummaries.map((event) => event.ulpinId)).to.deep.equal(ulpinIds);
+      expect(portfolio).to.not.be.null;
+      expect(portfolio.parcelCount).to.equal(2);
+      expect(portfolio.totalAreaSqm.toNumber()).to.equal(2000);
+    });
+
+    it("Rejects parcels that belong to another owner", async () => {
+      const otherUlpin = "GJPORTFOLIO00000003";
+      await registerParcel(otherUlpin, provider.wallet.publicKey);
+
+      try {
+        await program.methods
+          .emitOwnerPortfolio(owner, [ulpinIds[0], otherUlpin])
+          .accounts({})
+          .remainingAccounts(parcelAccounts([ulpinIds[0], otherUlpin]))
+          .rpc();
+        expect.fail("Should have rejected a parcel owned by someone else");
+      } catch (error) {
+        expect(error.message).to.include("PortfolioOwnerMismatch");
+      }
+    });
+  });
+
+  describe("dispute arbitration", () => {
+    const arbiter = anchor.web3.Keypair.generate();
+    const claimant = anchor.web3.Keypair.generate().publicKey;
+    const evidenceHash = Array(32).fill(7);
+
+    const findArbiterPDA = (key: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync([Buffer.from("arbiter"), key.toBuffer()], program.programId)[0];
+
+    const findArbitrationPDA = (ulpinId: string): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("arbitration"), findLandParcelPDA(ulpinId).toBuffer()],
+        program.programId
+      )[0];
+
+    const openArbitration = (ulpinId: string) =>
+      program.methods
+        .openArbitration(ulpinId, claimant, evidenceHash)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          arbitration: findArbitrationPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const submitRuling = (ulpinId: string, ruling: object) =>
+      program.methods
+        .submitArbiterRuling(ulpinId, ruling as any)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          arbitration: findArbitrationPDA(ulpinId),
+          arbiterAccount: findArbiterPDA(arbiter.publicKey),
+          arbiter: arbiter.publicKey,
+          treasury: treasuryPDA,
+          ownershipHistory: findOwnershipHistoryPDA(ulpinId),
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([arbiter])
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .registerArbiter(arbiter.publicKey)
+        .accounts({
+          arbiterAccount: findArbiterPDA(arbiter.publicKey),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Blocks transfers and reassigns ownership when the arbiter rules for the claimant", async () => {
+      const ulpinId = "GJARBITRATION000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+      await openArbitration(ulpinId);
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have blocked the transfer during arbitration");
+      } catch (error) {
+        expect(error.message).to.include("ParcelUnderArbitration");
+      }
+
+      await submitRuling(ulpinId, { transferToClaimant: {} });
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(claimant.toString());
+      expect(landParcel.underArbitration).to.be.false;
+      const history = await program.account.ownershipHistory.fetch(findOwnershipHistoryPDA(ulpinId));
+      expect(history.entries.map((entry) => entry.owner.toString())).to.deep.equal([claimant.toString()]);
+    });
+
+    it("Keeps the current owner when the arbiter upholds the status quo", async () => {
+      const ulpinId = "GJARBITRATION000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await openArbitration(ulpinId);
+
+      await submitRuling(ulpinId, { upholdOwner: {} });
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(landParcel.underArbitration).to.be.false;
+      const arbitration = await program.account.arbitration.fetch(findArbitrationPDA(ulpinId));
+      expect(arbitration.ruling).to.deep.equal({ upholdOwner: {} });
+    });
+  });
+
+  describe("subsurface rights", () => {
+    const ulpinId = "GJSUBSURFACE0000001";
+
+    const transferSubsurface = (ulpin: string, newSubsurfaceOwner: PublicKey) =>
+      program.methods
+        .transferSubsurfaceRights(ulpin, newSubsurfaceOwner)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpin),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    before(async () => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+    });
+
+    it("Defaults subsurface rights to the registered owner", async () => {
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.subsurfaceOwner.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+
+    it("Transfers subsurface rights without touching surface ownership", async () => {
+      const miningLessee = anchor.web3.Keypair.generate().publicKey;
+
+      await transferSubsurface(ulpinId, miningLessee);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.subsurfaceOwner.toString()).to.equal(miningLessee.toString());
+      expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+
+    it("Refuses to transfer subsurface rights while the parcel is disputed", async () => {
+      const disputedUlpin = "GJSUBSURFACE0000002";
+      const claimant = anchor.web3.Keypair.generate();
+      await registerParcel(disputedUlpin, provider.wallet.publicKey);
+      await verifyParcel(disputedUlpin);
+      await program.methods
+        .openDispute("Mineral rights claimed under an older lease")
+        .accounts({ landParcel: findLandParcelPDA(disputedUlpin), claimant: claimant.publicKey })
+        .signers([claimant])
+        .rpc();
+
+      try {
+        await transferSubsurface(disputedUlpin, anchor.web3.Keypair.generate().publicKey);
+        expect.fail("Should have refused a subsurface transfer on a disputed parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelUnderDispute");
+      }
+    });
+  });
+
+  describe("easements", () => {
+    const ulpinId = "GJEASEMENT000000001";
+    const utility = anchor.web3.Keypair.generate().publicKey;
+
+    const findEasementRegistryPDA = (ulpin: string): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("easements"), findLandParcelPDA(ulpin).toBuffer()],
+        program.programId
+      )[0];
+
+    before(async () => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    });
+
+    it("Records an easement, reflects it in the parcel summary and blocks transfers", async () => {
+      await program.methods
+        .addEasement(ulpinId, { utilityAccess: {} }, utility, Array(32).fill(3), true)
+        .accounts({
+          easementRegistry: findEasementRegistryPDA(ulpinId),
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const registry = await program.account.easementRegistry.fetch(findEasementRegistryPDA(ulpinId));
+      expect(registry.easements).to.have.lengthOf(1);
+      expect(registry.easements[0].beneficiary.toString()).to.equal(utility.toString());
+
+      let summary: any = null;
+      const listener = program.addEventListener("ParcelSummary", (event) => {
+        summary = event;
+      });
+      await program.methods
+        .emitOwnerPortfolio(provider.wallet.publicKey, [ulpinId])
+        .accounts({})
+        .remainingAccounts([{ pubkey: findLandParcelPDA(ulpinId), isWritable: false, isSigner: false }])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(summary.easementCount).to.equal(1);
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have blocked the transfer");
+      } catch (error) {
+        expect(error.message).to.include("EasementBlocksTransfer");
+      }
+    });
+
+    it("Removes an easement", async () => {
+      await program.methods
+        .removeEasement(ulpinId, 0)
+        .accounts({
+          easementRegistry: findEasementRegistryPDA(ulpinId),
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const registry = await program.account.easementRegistry.fetch(findEasementRegistryPDA(ulpinId));
+      expect(registry.easements).to.have.lengthOf(0);
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.easementCount).to.equal(0);
+      expect(landParcel.transferBlockingEasements).to.equal(0);
+    });
+  });
+
+  describe("rapid transfer tripwire", () => {
+    const setLimit = (limit: object | null) =>
+      program.methods
+        .setRapidTransferLimit(limit as any)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    };
+
+    const transferTimes = async (ulpinId: string, times: number) => {
+      for (let i = 0; i < times; i++) {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+      }
+    };
+
+    before(async () => {
+      await setLimit({
+        maxTransfers: 2,
+        windowSeconds: new anchor.BN(3600),
+        reviewSeconds: new anchor.BN(86400),
+      });
+    });
+
+    after(async () => {
+      await setLimit(null);
+    });
+
+    it("Freezes a parcel that changes hands too often", async () => {
+      const ulpinId = "GJRAPID000000000001";
+      await prepareMintedParcel(ulpinId);
+
+      let triggered: any = null;
+      const listener = program.addEventListener("AutoFreezeTriggered", (event) => {
+        triggered = event;
+      });
+      await transferTimes(ulpinId, 3);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(triggered).to.not.be.null;
+      expect(triggered.transferCount).to.equal(3);
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.quarantineUntil.toNumber()).to.be.greaterThan(Math.floor(Date.now() / 1000));
+
+      try {
+        await transferTimes(ulpinId, 1);
+        expect.fail("Should have blocked transfers during review");
+      } catch (error) {
+        expect(error.message).to.include("ParcelQuarantined");
+      }
+    });
+
+    it("Leaves a parcel alone at a normal cadence", async () => {
+      const ulpinId = "GJRAPID000000000002";
+      await prepareMintedParcel(ulpinId);
+
+      await transferTimes(ulpinId, 2);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.quarantineUntil.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("joint-family ownership", () => {
+    const karta = anchor.web3.Keypair.generate();
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    };
+
+    const setHuf = (ulpinId: string) =>
+      program.methods
+        .setOwnershipType(ulpinId, { huf: { karta: karta.publicKey } })
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Requires the karta's signature to transfer an HUF parcel", async () => {
+      const ulpinId = "GJHUF00000000000001";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await prepareMintedParcel(ulpinId);
+      await setHuf(ulpinId);
+
+      try {
+        await transferParcel(ulpinId, newOwner).rpc();
+        expect.fail("Should have required the karta's signature");
+      } catch (error) {
+        expect(error.message).to.include("KartaSignatureRequired");
+      }
+
+      await transferParcel(ulpinId, newOwner)
+        .accounts({ karta: karta.publicKey })
+        .signers([karta])
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+      expect(landParcel.ownershipType).to.deep.equal({ individual: {} });
+    });
+
+    it("Transfers an individually owned parcel without a karta", async () => {
+      const ulpinId = "GJHUF00000000000002";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await prepareMintedParcel(ulpinId);
+
+      await transferParcel(ulpinId, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("treasury authority handoff", () => {
+    const newAuthority = anchor.web3.Keypair.generate();
+
+    const propose = (proposed: PublicKey, authority: anchor.web3.Keypair | null = null) => {
+      const builder = program.methods
+        .proposeTreasuryAuthority(proposed)
+        .accounts({
+          treasury: treasuryPDA,
+          authority: authority ? authority.publicKey : provider.wallet.publicKey,
+        });
+      return authority ? builder.signers([authority]).rpc() : builder.rpc();
+    };
+
+    const accept = (signer: anchor.web3.Keypair | null = null) => {
+      const builder = program.methods
+        .acceptTreasuryAuthority()
+        .accounts({
+          treasury: treasuryPDA,
+          newAuthority: signer ? signer.publicKey : provider.wallet.publicKey,
+        });
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+
+    it("Rejects acceptance when no handoff is pending", async () => {
+      try {
+        await accept(newAuthority);
+        expect.fail("Should have required a pending authority");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingAuthority");
+      }
+    });
+
+    it("Lets the current authority cancel a proposal", async () => {
+      await propose(newAuthority.publicKey);
+      await program.methods
+        .cancelTreasuryAuthorityProposal()
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+      const treasury = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasury.pendingAuthority).to.be.null;
+    });
+
+    it("Hands the treasury over only when the proposed key accepts", async () => {
+      await propose(newAuthority.publicKey);
+
+      try {
+        await accept(anchor.web3.Keypair.generate());
+        expect.fail("Should have rejected a signer other than the pending authority");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      await accept(newAuthority);
+
+      let treasury = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasury.authority.toString()).to.equal(newAuthority.publicKey.toString());
+      expect(treasury.pendingAuthority).to.be.null;
+
+      // Hand the treasury back so later tests keep using the provider wallet
+      await propose(provider.wallet.publicKey, newAuthority);
+      await accept();
+
+      treasury = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasury.authority.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+  });
+
+  describe("generic parcel change events", () => {
+    it("Emits ParcelStateChanged with the matching change type for each mutation", async () => {
+      const ulpinId = "GJSTATECHANGE000001";
+      const events: any[] = [];
+      const listener = program.addEventListener("ParcelStateChanged", (event) => {
+        if (event.ulpinId === ulpinId) {
+          events.push(event);
+        }
+      });
+
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await program.methods
+        .setOccupancy(ulpinId, { vacant: {} }, null)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          signer: provider.wallet.publicKey,
+        })
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(events.map((event) => event.changeType)).to.deep.equal([
+        { registered: {} },
+        { verified: {} },
+        { occupancyUpdated: {} },
+      ]);
+      events.forEach((event) => {
+        expect(event.actor.toString()).to.equal(provider.wallet.publicKey.toString());
+      });
+    });
+
+    it("Emits Frozen and Thawed for freezes and forced thaws", async () => {
+      const ulpinId = "GJSTATECHANGE000002";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      const freezeAccounts = {
+        landParcel: findLandParcelPDA(ulpinId),
+        userTokenAccount: nftTokenAccount,
+        nftMint,
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+      const events: any[] = [];
+      const listener = program.addEventListener("ParcelStateChanged", (event) => {
+        if (event.ulpinId === ulpinId) {
+          events.push(event);
+        }
+      });
+
+      await program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts(freezeAccounts)
+        .rpc();
+      await program.methods.forceThawLandNft("Dispute settled").accounts(freezeAccounts).rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(events.map((event) => event.changeType)).to.deep.equal([{ frozen: {} }, { thawed: {} }]);
+    });
+  });
+
+  describe("per-operation pausing", () => {
+    const PAUSE_MINTING = 1 << 1;
+
+    const setPaused = (flags: number) =>
+      program.methods
+        .setPaused(flags)
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    after(async () => {
+      await setPaused(0);
+    });
+
+    it("Pauses minting while verification continues", async () => {
+      const ulpinId = "GJPAUSE000000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await setPaused(PAUSE_MINTING);
+
+      await verifyParcel(ulpinId);
+
+      try {
+        await mintParcel(ulpinId);
+        expect.fail("Should have rejected minting while paused");
+      } catch (error) {
+        expect(error.message).to.include("OperationPaused");
+      }
+
+      await setPaused(0);
+      await mintParcel(ulpinId);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.isVerified).to.be.true;
+      expect(landParcel.nftMinted).to.be.true;
+    });
+  });
+
+  describe("ownership transfer events", () => {
+    it("Reports the original owner as previous_owner", async () => {
+      const ulpinId = "GJPREVOWNER00000001";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+
+      let transferred: any = null;
+      const listener = program.addEventListener("OwnershipTransferred", (event) => {
+        if (event.ulpinId === ulpinId) {
+          transferred = event;
+        }
+      });
+      await transferParcel(ulpinId, newOwner).rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(transferred).to.not.be.null;
+      expect(transferred.previousOwner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(transferred.newOwner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("ownership transfer authorization", () => {
+    const owner = anchor.web3.Keypair.generate();
+
+    // A transferring owner pays for the parcel's first history page
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(owner.publicKey, LAMPORTS_PER_SOL)
+      );
+    });
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, owner.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId, owner.publicKey);
+    };
+
+    it("Lets the current owner transfer their parcel", async () => {
+      const ulpinId = "GJTRANSFERAUTH00001";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await prepareMintedParcel(ulpinId);
+
+      await transferParcel(ulpinId, newOwner)
+        .accounts({ authority: owner.publicKey })
+        .signers([owner])
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Lets the treasury authority transfer any parcel", async () => {
+      const ulpinId = "GJTRANSFERAUTH00002";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await prepareMintedParcel(ulpinId);
+
+      await transferParcel(ulpinId, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Rejects transfers signed by anyone else", async () => {
+      const ulpinId = "GJTRANSFERAUTH00003";
+      const stranger = anchor.web3.Keypair.generate();
+      await prepareMintedParcel(ulpinId);
+
+      try {
+        await transferParcel(ulpinId, stranger.publicKey)
+          .accounts({ authority: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have rejected an unrelated signer");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedOwnershipTransfer");
+      }
+    });
+  });
+
+  describe("fee schedule", () => {
+    const setFees = (baseFee: number, perSqmFee: number) =>
+      program.methods
+        .updateFeeConfig(new anchor.BN(baseFee), new anchor.BN(perSqmFee))
+        .accounts({
+          feeConfig: feeConfigPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    const mintAndMeasureFee = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey, { areaSqm: 1000 });
+      await verifyParcel(ulpinId);
+      const before = await getAccount(connection, treasuryTokenAccount);
+      await mintParcel(ulpinId);
+      const after = await getAccount(connection, treasuryTokenAccount);
+      return Number(after.amount) - Number(before.amount);
+    };
+
+    after(async () => {
+      await setFees(100000, 10);
+    });
+
+    it("Charges the default fees before a fee config exists", async () => {
+      expect(await program.account.feeConfig.fetchNullable(feeConfigPDA)).to.be.null;
+      expect(await mintAndMeasureFee("GJFEECONFIG00000001")).to.equal(100000 + 1000 * 10);
+    });
+
+    it("Charges the configured fees once initialized and updated", async () => {
+      await program.methods
+        .initializeFeeConfig(new anchor.BN(200000), new anchor.BN(20))
+        .accounts({
+          feeConfig: feeConfigPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect(await mintAndMeasureFee("GJFEECONFIG00000002")).to.equal(200000 + 1000 * 20);
+
+      await setFees(50000, 5);
+      expect(await mintAndMeasureFee("GJFEECONFIG00000003")).to.equal(50000 + 1000 * 5);
+    });
+  });
+
+  describe("forced thaw", () => {
+    const ulpinId = "GJFORCETHAW00000001";
+    let nftMint: PublicKey;
+    let nftTokenAccount: PublicKey;
+
+    const forceThaw = (authority: anchor.web3.Keypair | null, reason: string) => {
+      const builder = program.methods
+        .forceThawLandNft(reason)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: authority ? authority.publicKey : provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        });
+      return authority ? builder.signers([authority]).rpc() : builder.rpc();
+    };
+
+    before(async () => {
+      ({ nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId));
+      await program.methods
+        .freezeLandNft(new anchor.BN(86400), Array(32).fill(0), 1, "Interim injunction")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    });
+
+    it("Rejects a forced thaw from anyone but the freeze authority", async () => {
+      try {
+        await forceThaw(anchor.web3.Keypair.generate(), "Unauthorized release");
+        expect.fail("Should have required the freeze authority");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Releases the freeze before it expires", async () => {
+      await forceThaw(null, "High Court order for immediate release");
+
+      const tokenAccount = await getAccount(connection, nftTokenAccount);
+      expect(tokenAccount.isFrozen).to.be.false;
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.freezeStartTimestamp).to.be.null;
+      expect(landParcel.freezeDuration).to.be.null;
+    });
+  });
+
+  describe("freeze reasons", () => {
+    const reasons = [
+      { code: 0, variant: { dispute: {} } },
+      { code: 1, variant: { courtOrder: {} } },
+      { code: 2, variant: { fraudInvestigation: {} } },
+      { code: 3, variant: { administrative: {} } },
+      { code: 4, variant: { taxDefault: {} } },
+    ];
+
+    const freeze = (ulpinId: string, nftMint: PublicKey, nftTokenAccount: PublicKey, code: number) =>
+      program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), code, "Recorded by the district registrar")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    reasons.forEach(({ code, variant }) => {
+      it(`Records freeze reason code ${code}`, async () => {
+        const ulpinId = `GJFREEZEREASON0000${code}`;
+        const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+
+        await freeze(ulpinId, nftMint, nftTokenAccount, code);
+
+        const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(landParcel.freezeReason).to.deep.equal(variant);
+      });
+    });
+
+    it("Rejects an out-of-range reason code", async () => {
+      const ulpinId = "GJFREEZEREASON00009";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+
+      try {
+        await freeze(ulpinId, nftMint, nftTokenAccount, 5);
+        expect.fail("Should have rejected an unknown reason code");
+      } catch (error) {
+        expect(error.message).to.include("InvalidFreezeReason");
+      }
+    });
+  });
+
+  describe("thaw by reason", () => {
+    const TAX_DEFAULT = 4;
+    const COURT_ORDER = 1;
+
+    const freezeFor = async (ulpinId: string, code: number) => {
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      await program.methods
+        .freezeLandNft(new anchor.BN(1), Array(32).fill(0), code, "Recorded by the district registrar")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      return { ulpinId, nftMint, nftTokenAccount };
+    };
+
+    it("Thaws only tax-default freezes and leaves court orders in place", async () => {
+      const taxDefault = await freezeFor("GJTHAWREASON0000001", TAX_DEFAULT);
+      const courtOrder = await freezeFor("GJTHAWREASON0000002", COURT_ORDER);
+      const parcels = [taxDefault, courtOrder];
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      let emitted: any = null;
+      const listener = program.addEventListener("ParcelsThawedByReason", (event) => {
+        emitted = event;
+      });
+      await program.methods
+        .thawByReasonBatch(TAX_DEFAULT, parcels.map((parcel) => parcel.ulpinId), false)
+        .accounts({
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          parcels.flatMap((parcel) => [
+            { pubkey: findLandParcelPDA(parcel.ulpinId), isWritable: true, isSigner: false },
+            { pubkey: parcel.nftTokenAccount, isWritable: true, isSigner: false },
+            { pubkey: parcel.nftMint, isWritable: false, isSigner: false },
+          ])
+        )
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect((await getAccount(connection, taxDefault.nftTokenAccount)).isFrozen).to.be.false;
+      expect((await getAccount(connection, courtOrder.nftTokenAccount)).isFrozen).to.be.true;
+      const courtParcel = await program.account.landParcel.fetch(findLandParcelPDA(courtOrder.ulpinId));
+      expect(courtParcel.freezeReason).to.deep.equal({ courtOrder: {} });
+
+      expect(emitted).to.not.be.null;
+      expect(emitted.thawedCount).to.equal(1);
+      expect(emitted.skipped).to.deep.equal([courtOrder.ulpinId]);
+    });
+  });
+
+  describe("transfers of frozen parcels", () => {
+    it("Rejects transfers until the parcel is thawed", async () => {
+      const ulpinId = "GJFROZENTRANSFER001";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      const freezeAccounts = {
+        landParcel: findLandParcelPDA(ulpinId),
+        userTokenAccount: nftTokenAccount,
+        nftMint,
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+
+      await program.methods
+        .freezeLandNft(new anchor.BN(86400), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts(freezeAccounts)
+        .rpc();
+
+      try {
+        await transferParcel(ulpinId, newOwner).rpc();
+        expect.fail("Should have rejected the transfer of a frozen parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelFrozen");
+      }
+
+      await program.methods
+        .forceThawLandNft("Dispute settled")
+        .accounts(freezeAccounts)
+        .rpc();
+      await transferParcel(ulpinId, newOwner).rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+  });
+
+  describe("batch registration", () => {
+    const parcelInput = (ulpinId: string, areaSqm: number) => ({
+      ulpinId,
+      areaSqm: new anchor.BN(areaSqm),
+      district: "Ahmedabad",
+      taluka: "City",
+      village: "Village1",
+      owner: provider.wallet.publicKey,
+      documentHash,
+      bbox,
+      landUse: { agricultural: {} },
+    });
+
+    const registerBatch = (inputs: any[]) =>
+      program.methods
+        .registerLandParcelsBatch("Ahmedabad", inputs)
+        .accounts({
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
+          districtRules: findDistrictRulesPDA("Ahmedabad"),
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          inputs.map((input) => ({
+            pubkey: findLandParcelPDA(input.ulpinId),
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+
+    it("Registers several parcels in one instruction", async () => {
+      const inputs = [
+        parcelInput("GJBATCH000000000001", 1000),
+        parcelInput("GJBATCH000000000002", 2000),
+        parcelInput("GJBATCH000000000003", 3000),
+      ];
+      const before = await program.account.treasury.fetch(treasuryPDA);
+      const statsBefore = await program.account.districtStats.fetch(findDistrictStatsPDA("Ahmedabad"));
+
+      await registerBatch(inputs);
+
+      const after = await program.account.treasury.fetch(treasuryPDA);
+      expect(after.landParcelCount.toNumber()).to.equal(before.landParcelCount.toNumber() + 3);
+      const statsAfter = await program.account.districtStats.fetch(findDistrictStatsPDA("Ahmedabad"));
+      expect(statsAfter.registeredCount.toNumber()).to.equal(statsBefore.registeredCount.toNumber() + 3);
+      for (const input of inputs) {
+        const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(input.ulpinId));
+        expect(landParcel.areaSqm.toNumber()).to.equal(input.areaSqm.toNumber());
+        expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      }
+    });
+
+    it("Rejects batches larger than the limit", async () => {
+      const inputs = [...Array(6).keys()].map((i) => parcelInput(`GJBATCHLARGE000000${i}`, 1000));
+
+      try {
+        await registerBatch(inputs);
+        expect.fail("Should have rejected an oversized batch");
+      } catch (error) {
+        expect(error.message).to.include("BatchTooLarge");
+      }
+    });
+  });
+
+  describe("closing parcels", () => {
+    const closeParcel = (ulpinId: string) =>
+      program.methods
+        .closeLandParcel()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Closes a mistaken registration and refunds its rent", async () => {
+      const ulpinId = "GJCLOSE000000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      const before = await program.account.treasury.fetch(treasuryPDA);
+
+      await closeParcel(ulpinId);
+
+      expect(await program.account.landParcel.fetchNullable(findLandParcelPDA(ulpinId))).to.be.null;
+      const after = await program.account.treasury.fetch(treasuryPDA);
+      expect(after.landParcelCount.toNumber()).to.equal(before.landParcelCount.toNumber() - 1);
+    });
+
+    it("Refuses to close a parcel with a minted NFT", async () => {
+      const ulpinId = "GJCLOSE000000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+
+      try {
+        await closeParcel(ulpinId);
+        expect.fail("Should have refused to close a minted parcel");
+      } catch (error) {
+        expect(error.message).to.include("CannotCloseMintedParcel");
+      }
+    });
+
+    it("Refuses to close a parcel that is under a hold", async () => {
+      const ulpinId = "GJCLOSE000000000003";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+      await program.methods
+        .quarantineParcel(ulpinId, new anchor.BN(3600), "Registration under review")
+        .accounts({ landParcel: landParcelPDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+      try {
+        await closeParcel(ulpinId);
+        expect.fail("Should have refused to close a quarantined parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelQuarantined");
+      }
+    });
+  });
+
+  describe("subdivision", () => {
+    const heirA = anchor.web3.Keypair.generate().publicKey;
+    const heirB = anchor.web3.Keypair.generate().publicKey;
+
+    const subdivide = (parentUlpin: string, children: { ulpinId: string; areaSqm: number; owner: PublicKey }[]) =>
+      program.methods
+        .subdivideParcel(
+          children.map((child) => ({
+            ulpinId: child.ulpinId,
+            areaSqm: new anchor.BN(child.areaSqm),
+            owner: child.owner,
+            documentHash,
+          }))
+        )
+        .accounts({
+          parentParcel: findLandParcelPDA(parentUlpin),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
+          districtRules: findDistrictRulesPDA("Ahmedabad"),
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          children.map((child) => ({
+            pubkey: findLandParcelPDA(child.ulpinId),
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+
+    it("Splits a parcel between heirs and freezes the parent", async () => {
+      const parentUlpin = "GJSUBDIVIDE00000001";
+      await registerParcel(parentUlpin, provider.wallet.publicKey, { areaSqm: 1000 });
+      await verifyParcel(parentUlpin);
+      await mintParcel(parentUlpin);
+
+      await subdivide(parentUlpin, [
+        { ulpinId: "GJSUBDIVIDE00000002", areaSqm: 600, owner: heirA },
+        { ulpinId: "GJSUBDIVIDE00000003", areaSqm: 400, owner: heirB },
+      ]);
+
+      const parent = await program.account.landParcel.fetch(findLandParcelPDA(parentUlpin));
+      expect(parent.subdivided).to.be.true;
+      const child = await program.account.landParcel.fetch(findLandParcelPDA("GJSUBDIVIDE00000002"));
+      expect(child.areaSqm.toNumber()).to.equal(600);
+      expect(child.owner.toString()).to.equal(heirA.toString());
+      expect(Buffer.from(child.parentUlpin).toString().replace(/\0+$/, "")).to.equal(parentUlpin);
+      expect(child.district).to.deep.equal(parent.district);
+      expect(child.documentHash).to.deep.equal(documentHash);
+
+      try {
+        await transferParcel(parentUlpin, heirA).rpc();
+        expect.fail("Should have blocked transfers of the subdivided parent");
+      } catch (error) {
+        expect(error.message).to.include("SubdividedParcelNotTransferable");
+      }
+    });
+
+    it("Rejects children whose areas do not add up to the parent", async () => {
+      const parentUlpin = "GJSUBDIVIDE00000004";
+      await registerParcel(parentUlpin, provider.wallet.publicKey, { areaSqm: 1000 });
+
+      try {
+        await subdivide(parentUlpin, [
+          { ulpinId: "GJSUBDIVIDE00000005", areaSqm: 600, owner: heirA },
+          { ulpinId: "GJSUBDIVIDE00000006", areaSqm: 300, owner: heirB },
+        ]);
+        expect.fail("Should have rejected the area mismatch");
+      } catch (error) {
+        expect(error.message).to.include("SubdivisionAreaMismatch");
+      }
+    });
+  });
+
+  describe("merging parcels", () => {
+    const merge = (resultUlpin: string, sourceUlpins: string[]) =>
+      program.methods
+        .mergeParcels(resultUlpin, "Ahmedabad", sourceUlpins, documentHash)
+        .accounts({
+          resultParcel: findLandParcelPDA(resultUlpin),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
+          districtRules: findDistrictRulesPDA("Ahmedabad"),
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          sourceUlpins.map((ulpinId) => ({
+            pubkey: findLandParcelPDA(ulpinId),
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+
+    const prepareVerifiedParcel = async (
+      ulpinId: string,
+      owner: PublicKey,
+      options: { areaSqm?: number; village?: string } = {}
+    ) => {
+      await registerParcel(ulpinId, owner, options);
+      await verifyParcel(ulpinId);
+    };
+
+    it("Consolidates adjacent plots into a new parcel", async () => {
+      const sources = ["GJMERGE000000000001", "GJMERGE000000000002"];
+      await prepareVerifiedParcel(sources[0], provider.wallet.publicKey, { areaSqm: 700 });
+      await prepareVerifiedParcel(sources[1], provider.wallet.publicKey, { areaSqm: 300 });
+
+      await merge("GJMERGE000000000003", sources);
+
+      const merged = await program.account.landParcel.fetch(findLandParcelPDA("GJMERGE000000000003"));
+      expect(merged.areaSqm.toNumber()).to.equal(1000);
+      expect(merged.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(merged.documentHash).to.deep.equal(documentHash);
+      for (const ulpinId of sources) {
+        const source = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(source.merged).to.be.true;
+      }
+    });
+
+    it("Rejects parcels with different owners", async () => {
+      const sources = ["GJMERGE000000000004", "GJMERGE000000000005"];
+      await prepareVerifiedParcel(sources[0], provider.wallet.publicKey);
+      await prepareVerifiedParcel(sources[1], anchor.web3.Keypair.generate().publicKey);
+
+      try {
+        await merge("GJMERGE000000000006", sources);
+        expect.fail("Should have rejected mixed owners");
+      } catch (error) {
+        expect(error.message).to.include("MergeOwnerMismatch");
+      }
+    });
+
+    it("Rejects parcels in different villages", async () => {
+      const sources = ["GJMERGE000000000007", "GJMERGE000000000008"];
+      await prepareVerifiedParcel(sources[0], provider.wallet.publicKey);
+      await prepareVerifiedParcel(sources[1], provider.wallet.publicKey, { village: "Village2" });
+
+      try {
+        await merge("GJMERGE000000000009", sources);
+        expect.fail("Should have rejected mixed villages");
+      } catch (error) {
+        expect(error.message).to.include("MergeLocationMismatch");
+      }
+    });
+  });
+
+  describe("document hash", () => {
+    const ulpinId = "GJDOCHASH0000000001";
+
+    before(async () => {
+      await program.methods
+        .registerLandParcel(
+          ulpinId,
+          new anchor.BN(1000),
+          "Ahmedabad",
+          "City",
+          "Village1",
+          provider.wallet.publicKey,
+          Array(32).fill(0),
+          bbox.minLat,
+          bbox.minLon,
+          bbox.maxLat,
+          bbox.maxLon,
+          LandUse.agricultural
+        )
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
+          authority: provider.wallet.publicKey,
+          districtRules: findDistrictRulesPDA("Ahmedabad"),
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Rejects verification without a document hash", async () => {
+      try {
+        await verifyParcel(ulpinId);
+        expect.fail("verification should require a document hash");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("MissingDocumentHash");
+      }
+    });
+
+    it("Records an updated document hash", async () => {
+      const newHash = Array(32).fill(7);
+      await program.methods
+        .updateDocumentHash(newHash)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(parcel.documentHash).to.deep.equal(newHash);
+
+      await verifyParcel(ulpinId);
+      const verified = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(verified.isVerified).to.be.true;
+    });
+  });
+
+  describe("verification quorum", () => {
+    const verifierA = anchor.web3.Keypair.generate();
+    const verifierB = anchor.web3.Keypair.generate();
+
+    const setQuorum = (verifiers: PublicKey[], requiredApprovals: number) =>
+      program.methods
+        .setVerificationQuorum(verifiers, requiredApprovals)
+        .accounts({
+          verifierRegistry: verifierRegistryPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const approve = async (ulpinId: string, verifier: anchor.web3.Keypair) =>
+      program.methods
+        .verifyLandParcel(ulpinId)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: verifier.publicKey,
+          owner: null,
+          accreditedFirm: null,
+          verificationState: findVerificationStatePDA(findLandParcelPDA(ulpinId)),
+          verifierRegistry: verifierRegistryPDA,
+          districtStats: await districtStatsFor(ulpinId),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([verifier])
+        .rpc();
+
+    before(async () => {
+      for (const verifier of [verifierA, verifierB]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(verifier.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+      await setQuorum([verifierA.publicKey, verifierB.publicKey], 2);
+    });
+
+    after(async () => {
+      await setQuorum([], 0);
+    });
+
+    it("Rejects a quorum larger than the verifier list", async () => {
+      try {
+        await setQuorum([verifierA.publicKey], 2);
+        expect.fail("quorum should not exceed the verifier count");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("InvalidQuorum");
+      }
+    });
+
+    it("Verifies only once the quorum is reached", async () => {
+      const ulpinId = "GJQUORUM00000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      await approve(ulpinId, verifierA);
+      let parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(parcel.isVerified).to.be.false;
+
+      try {
+        await approve(ulpinId, verifierA);
+        expect.fail("duplicate approval should be rejected");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("AlreadyApproved");
+      }
+
+      await approve(ulpinId, verifierB);
+      parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(parcel.isVerified).to.be.true;
+    });
+
+    it("Ignores a stale vote until it is re-cast", async () => {
+      const ulpinId = "GJQUORUM00000000003";
+      const setVoteValidity = (seconds: number) =>
+        program.methods
+          .setVoteValidity(new anchor.BN(seconds))
+          .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+          .rpc();
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await setVoteValidity(2);
+
+      try {
+        await approve(ulpinId, verifierA);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        await approve(ulpinId, verifierB);
+        let parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(parcel.isVerified).to.be.false;
+        const state = await program.account.verificationState.fetch(
+          findVerificationStatePDA(findLandParcelPDA(ulpinId))
+        );
+        expect(state.approvals.map((vote) => vote.verifier.toString())).to.deep.equal([
+          verifierB.publicKey.toString(),
+        ]);
+
+        await approve(ulpinId, verifierA);
+        parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(parcel.isVerified).to.be.true;
+      } finally {
+        await setVoteValidity(0);
+      }
+    });
+
+    it("Drops votes from verifiers removed from the registry", async () => {
+      const ulpinId = "GJQUORUM00000000004";
+      const verifierC = anchor.web3.Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(verifierC.publicKey, LAMPORTS_PER_SOL)
+      );
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await setQuorum([verifierA.publicKey, verifierB.publicKey, verifierC.publicKey], 2);
+
+      try {
+        await approve(ulpinId, verifierA);
+        await setQuorum([verifierB.publicKey, verifierC.publicKey], 2);
+
+        await approve(ulpinId, verifierB);
+        let parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(parcel.isVerified).to.be.false;
+
+        await approve(ulpinId, verifierC);
+        parcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(parcel.isVerified).to.be.true;
+      } finally {
+        await setQuorum([verifierA.publicKey, verifierB.publicKey], 2);
+      }
+    });
+
+    it("Rejects approvals from unregistered verifiers", async () => {
+      const ulpinId = "GJQUORUM00000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      const outsider = anchor.web3.Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(outsider.publicKey, LAMPORTS_PER_SOL)
+      );
+
+      try {
+        await approve(ulpinId, outsider);
+        expect.fail("outsider should not count towards the quorum");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("UnauthorizedVerifier");
+      }
+    });
+  });
+
+  describe("verifier management", () => {
+    const officer = anchor.web3.Keypair.generate().publicKey;
+
+    const updateRegistry = (method: "addVerifier" | "removeVerifier", verifier: PublicKey) =>
+      program.methods[method](verifier)
+        .accounts({
+          verifierRegistry: verifierRegistryPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("Adds and removes a verifier", async () => {
+      await updateRegistry("addVerifier", officer);
+      let registry = await program.account.verifierRegistry.fetch(verifierRegistryPDA);
+      expect(registry.verifiers.map((v) => v.toString())).to.include(officer.toString());
+
+      await updateRegistry("removeVerifier", officer);
+      registry = await program.account.verifierRegistry.fetch(verifierRegistryPDA);
+      expect(registry.verifiers.map((v) => v.toString())).to.not.include(officer.toString());
+    });
+
+    it("Rejects removing an unknown verifier", async () => {
+      try {
+        await updateRegistry("removeVerifier", anchor.web3.Keypair.generate().publicKey);
+        expect.fail("unknown verifier should not be removable");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("VerifierNotFound");
+      }
+    });
+
+    it("Rejects additions from anyone but the treasury authority", async () => {
+      const outsider = anchor.web3.Keypair.generate();
+      try {
+        await program.methods
+          .addVerifier(outsider.publicKey)
+          .accounts({
+            verifierRegistry: verifierRegistryPDA,
+            treasury: treasuryPDA,
+            authority: outsider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc();
+        expect.fail("outsider should not manage verifiers");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+  });
+
+  describe("ulpin seed derivation", () => {
+    for (const length of [1, 31, 32, 64]) {
+      it(`Registers a ${length}-byte ULPIN at a deterministic address`, async () => {
+        const ulpinId = `G${length}`.padEnd(length, "X").slice(0, length);
+        expect(findLandParcelPDA(ulpinId).equals(findLandParcelPDA(ulpinId))).to.be.true;
+
+        const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+
+        const parcel = await program.account.landParcel.fetch(landParcelPDA);
+        const stored = Buffer.from(parcel.ulpinId).toString().replace(/\0+$/, "");
+        expect(stored).to.equal(ulpinId);
+      });
+    }
+
+    it("Rejects an empty ULPIN", async () => {
+      try {
+        await registerParcel("", provider.wallet.publicKey);
+        expect.fail("empty ULPIN should be rejected");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("EmptyULPIN");
+      }
+    });
+  });
+
+  describe("parcel summary view", () => {
+    it("Returns a summary of the parcel", async () => {
+      const ulpinId = "GJSUMMARY0000000001";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey, { village: "Village2" });
+
+      const summary = await program.methods
+        .getParcelSummary()
+        .accounts({ landParcel: landParcelPDA })
+        .view();
+
+      expect(summary.ulpinId).to.equal(ulpinId);
+      expect(summary.areaSqm.toNumber()).to.equal(1000);
+      expect(summary.district).to.equal("Ahmedabad");
+      expect(summary.village).to.equal("Village2");
+      expect(summary.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(summary.isVerified).to.be.false;
+      expect(summary.isFrozen).to.be.false;
+      expect(summary.freezeReason).to.be.null;
+    });
+  });
+
+  describe("agricultural data", () => {
+    const findAgriDataPDA = (landParcel: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("agri_data"), landParcel.toBuffer()],
+        program.programId
+      )[0];
+
+    const setAgriData = (landParcel: PublicKey, irrigationType: number, soilClass: number, yieldKgPerHa: number) =>
+      program.methods
+        .setAgriData(irrigationType, soilClass, new anchor.BN(yieldKgPerHa))
+        .accounts({
+          agriData: findAgriDataPDA(landParcel),
+          landParcel,
+          treasury: treasuryPDA,
+          signer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("Records productivity data and includes it in the summary", async () => {
+      const landParcelPDA = await registerParcel("GJAGRIDATA000000001", provider.wallet.publicKey);
+
+      let emitted: any = null;
+      const listener = program.addEventListener("AgriDataUpdated", (event) => {
+        emitted = event;
+      });
+      // Drip irrigation, class II soil
+      await setAgriData(landParcelPDA, 3, 2, 4200);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(emitted).to.not.be.null;
+      expect(emitted.soilClass).to.equal(2);
+
+      const summary = await program.methods
+        .getParcelSummaryV2()
+        .accounts({ landParcel: landParcelPDA, agriData: findAgriDataPDA(landParcelPDA) })
+        .view();
+      expect(summary.landUse).to.deep.equal({ agricultural: {} });
+      expect(summary.agriData.irrigationType).to.deep.equal({ drip: {} });
+      expect(summary.agriData.soilClass).to.equal(2);
+      expect(summary.agriData.lastSeasonYieldKgPerHa.toNumber()).to.equal(4200);
+    });
+
+    it("Rejects productivity data for a non-agricultural parcel", async () => {
+      const landParcelPDA = await registerParcel("GJAGRIDATA000000002", provider.wallet.publicKey, {
+        landUse: LandUse.residential,
+      });
+
+      try {
+        await setAgriData(landParcelPDA, 0, 3, 1000);
+        expect.fail("Should have rejected agri data on a residential parcel");
+      } catch (error) {
+        expect(error.message).to.include("NotAgriculturalParcel");
+      }
+    });
+  });
+
+  describe("freeze authority rotation", () => {
+    const newAuthority = anchor.web3.Keypair.generate();
+
+    const rotate = (signer: anchor.web3.Keypair | null, next: PublicKey) => {
+      const builder = program.methods
+        .transferFreezeAuthority(next)
+        .accounts({
+          freezeAuthority: freezeAuthorityPDA,
+          authority: signer ? signer.publicKey : provider.wallet.publicKey,
+        });
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+
+    before(async () => {
+      await ensureFreezeAuthority();
+    });
+
+    it("Rejects rotation by anyone but the current authority", async () => {
+      try {
+        await rotate(newAuthority, newAuthority.publicKey);
+        expect.fail("Should have required the current freeze authority");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Hands the freeze authority to a new key and back", async () => {
+      await rotate(null, newAuthority.publicKey);
+      let freezeAuthority = await program.account.freezeAuthorityPda.fetch(freezeAuthorityPDA);
+      expect(freezeAuthority.authority.toString()).to.equal(newAuthority.publicKey.toString());
+
+      await rotate(newAuthority, provider.wallet.publicKey);
+      freezeAuthority = await program.account.freezeAuthorityPda.fetch(freezeAuthorityPDA);
+      expect(freezeAuthority.authority.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+  });
+
+  describe("freeze time remaining", () => {
+    const ulpinId = "GJFREEZELEFT0000001";
+
+    const remaining = async (): Promise<number> =>
+      (
+        await program.methods
+          .freezeTimeRemaining()
+          .accounts({ landParcel: findLandParcelPDA(ulpinId) })
+          .view()
+      ).toNumber();
+
+    it("Counts down the freeze and reports 0 when not frozen", async () => {
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      expect(await remaining()).to.equal(0);
+
+      await program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const secondsLeft = await remaining();
+      expect(secondsLeft).to.be.greaterThan(3500);
+      expect(secondsLeft).to.be.at.most(3600);
+    });
+  });
+
+  describe("freeze expiry in events", () => {
+    it("Reports the freeze start and expiry", async () => {
+      const ulpinId = "GJFREEZEEXPIRY00001";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+
+      let frozenEvent = null;
+      const listener = program.addEventListener("NFTFrozen", (event) => {
+        frozenEvent = event;
+      });
+
+      await program.methods
+        .freezeLandNft(new anchor.BN(7200), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: nftTokenAccount,
+          nftMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(frozenEvent).to.not.be.null;
+      expect(frozenEvent.freezeStart.toNumber()).to.equal(landParcel.freezeStartTimestamp.toNumber());
+      expect(frozenEvent.freezeExpiry.toNumber()).to.equal(frozenEvent.freezeStart.toNumber() + 7200);
+    });
+  });
+
+  describe("freeze state guards", () => {
+    const ulpinId = "GJFREEZEGUARD000001";
+    let freezeAccounts;
+
+    before(async () => {
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      freezeAccounts = {
+        landParcel: findLandParcelPDA(ulpinId),
+        userTokenAccount: nftTokenAccount,
+        nftMint,
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+    });
+
+    it("Rejects a freeze signed by anyone but the freeze authority", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+      try {
+        await program.methods
+          .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+          .accounts({ ...freezeAccounts, authority: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have required the freeze authority");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Rejects thawing a parcel that is not frozen", async () => {
+      try {
+        await program.methods.thawLandNft().accounts(freezeAccounts).rpc();
+        expect.fail("Should have rejected thawing an unfrozen parcel");
+      } catch (error) {
+        expect(error.message).to.include("NotFrozen");
+      }
+    });
+
+    it("Rejects freezing a parcel twice", async () => {
+      await program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts(freezeAccounts)
+        .rpc();
+
+      try {
+        await program.methods
+          .freezeLandNft(new anchor.BN(60), Array(32).fill(0), 0, "Second order")
+          .accounts(freezeAccounts)
+          .rpc();
+        expect.fail("Should have rejected a second freeze");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyFrozen");
+      }
+    });
+  });
+
+  describe("freeze duration bounds", () => {
+    const ulpinId = "GJFREEZEBOUNDS00001";
+    let freezeAccounts;
+
+    const freezeFor = (seconds: number) =>
+      program.methods
+        .freezeLandNft(new anchor.BN(seconds), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts(freezeAccounts)
+        .rpc();
+
+    before(async () => {
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      freezeAccounts = {
+        landParcel: findLandParcelPDA(ulpinId),
+        userTokenAccount: nftTokenAccount,
+        nftMint,
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+    });
+
+    for (const [label, seconds] of [
+      ["zero", 0],
+      ["negative", -60],
+      ["over-max", 5 * 365 * 86400 + 1],
+    ] as const) {
+      it(`Rejects a ${label} freeze duration`, async () => {
+        try {
+          await freezeFor(seconds);
+          expect.fail("Should have rejected the freeze duration");
+        } catch (error) {
+          expect(error.message).to.include("InvalidFreezeDuration");
+        }
+      });
+    }
+
+    it("Honours a lowered maximum", async () => {
+      const setMax = (seconds: number) =>
+        program.methods
+          .setMaxFreezeDuration(new anchor.BN(seconds))
+          .accounts({ freezeAuthority: freezeAuthorityPDA, authority: provider.wallet.publicKey })
+          .rpc();
+
+      await setMax(3600);
+      try {
+        await freezeFor(7200);
+        expect.fail("Should have rejected a freeze over the configured maximum");
+      } catch (error) {
+        expect(error.message).to.include("InvalidFreezeDuration");
+      } finally {
+        await setMax(5 * 365 * 86400);
+      }
+    });
+  });
+
+  describe("metadata URI updates", () => {
+    const ulpinId = "GJMETADATA000000001";
+    let freezeAccounts;
+
+    const updateUri = (uri: string, force = false) =>
+      program.methods
+        .updateMetadataUri(uri, force)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    before(async () => {
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      freezeAccounts = {
+        landParcel: findLandParcelPDA(ulpinId),
+        userTokenAccount: nftTokenAccount,
+        nftMint,
+        freezeAuthority: freezeAuthorityPDA,
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+    });
+
+    it("Stores the updated URI on an unfrozen parcel", async () => {
+      await updateUri("https://example.com/resurvey.json");
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.metadataUri).to.equal("https://example.com/resurvey.json");
+    });
+
+    it("Rejects URIs longer than 200 bytes", async () => {
+      try {
+        await updateUri("x".repeat(201));
+        expect.fail("Should have rejected an oversized URI");
+      } catch (error) {
+        expect(error.message).to.include("InvalidMetadataURI");
+      }
+    });
+
+    it("Rate-limits updates unless the authority forces them", async () => {
+      const setInterval = (seconds: number) =>
+        program.methods
+          .setMinMetadataUpdateInterval(new anchor.BN(seconds))
+          .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+          .rpc();
+      await setInterval(2);
+
+      try {
+        await updateUri("https://example.com/revaluation.json", true);
+        try {
+          await updateUri("https://example.com/too-soon.json");
+          expect.fail("Should have rejected a second update inside the interval");
+        } catch (error) {
+          expect(error.message).to.include("MetadataUpdateTooFrequent");
+        }
+
+        await updateUri("https://example.com/correction.json", true);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        await updateUri("https://example.com/later.json");
+
+        const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(landParcel.metadataUri).to.equal("https://example.com/later.json");
+      } finally {
+        await setInterval(0);
+      }
+    });
+
+    it("Rejects metadata updates while the parcel is frozen", async () => {
+      await program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts(freezeAccounts)
+        .rpc();
+
+      try {
+        await updateUri("https://example.com/during-freeze.json");
+        expect.fail("Should have rejected a metadata update on a frozen parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelFrozen");
+      }
+    });
+  });
+
+  describe("parcel NFT mint link", () => {
+    it("Records the mint and rejects freezing with an unrelated one", async () => {
+      const ulpinId = "GJMINTLINK000000001";
+      const { nftMint } = await prepareFreezableParcel(ulpinId);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMint.toString()).to.equal(nftMint.toString());
+
+      const otherMint = await createMint(connection, provider.wallet.payer, provider.wallet.publicKey, freezeAuthorityPDA, 0);
+      const otherTokenAccount = await createAccount(connection, provider.wallet.payer, otherMint, provider.wallet.publicKey);
+      try {
+        await program.methods
+          .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+          .accounts({
+            landParcel: findLandParcelPDA(ulpinId),
+            userTokenAccount: otherTokenAccount,
+            nftMint: otherMint,
+            freezeAuthority: freezeAuthorityPDA,
+            authority: provider.wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have rejected a mint that does not belong to the parcel");
+      } catch (error) {
+        expect(error.message).to.include("MintMismatch");
+      }
+    });
+  });
+
+  describe("fee exemptions", () => {
+    const feeFor = async (ulpinId: string, exemptionBps: number, coSigned: boolean): Promise<number> => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      const before = Number((await getAccount(connection, treasuryTokenAccount)).amount);
+      await mintParcel(
+        ulpinId,
+        provider.wallet.publicKey,
+        { exemptionAuthority: coSigned ? provider.wallet.publicKey : null },
+        exemptionBps
+      );
+      return Number((await getAccount(connection, treasuryTokenAccount)).amount) - before;
+    };
+
+    it("Applies the discount only with the authority's co-signature", async () => {
+      const fullFee = await feeFor("GJEXEMPT00000000001", 5000, false);
+      const discountedFee = await feeFor("GJEXEMPT00000000002", 5000, true);
+      expect(discountedFee).to.equal(fullFee / 2);
+
+      const exemptFee = await feeFor("GJEXEMPT00000000003", 10000, true);
+      expect(exemptFee).to.equal(0);
+    });
+
+    it("Rejects exemptions above 10000 basis points", async () => {
+      try {
+        await feeFor("GJEXEMPT00000000004", 10001, true);
+        expect.fail("Should have rejected an exemption over 100%");
+      } catch (error) {
+        expect(error.message).to.include("InvalidExemption");
+      }
+    });
+  });
+
+  describe("NFT reissue", () => {
+    const reissue = (ulpinId: string, newMint: PublicKey, ownerTokenAccount: PublicKey) =>
+      program.methods
+        .reissueLandNft(ulpinId, newMint)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          newMint,
+          ownerTokenAccount,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Reissues a burned NFT to the recorded owner", async () => {
+      const ulpinId = "GJREISSUE0000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      const { nftMint, nftTokenAccount } = await createNftMint();
+      await mintParcel(ulpinId, provider.wallet.publicKey, { nftMint });
+
+      await burn(connection, provider.wallet.payer, nftTokenAccount, nftMint, provider.wallet.payer, 1);
+      await program.methods
+        .recordNftBurn()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          nftMint,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      expect((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).nftMinted).to.be.false;
+
+      const replacement = await createNftMint();
+      await reissue(ulpinId, replacement.nftMint, replacement.nftTokenAccount);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMinted).to.be.true;
+      expect(landParcel.nftMint.toString()).to.equal(replacement.nftMint.toString());
+    });
+
+    it("Rejects reissuing for a parcel that was never minted", async () => {
+      const ulpinId = "GJREISSUE0000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      const replacement = await createNftMint();
+
+      try {
+        await reissue(ulpinId, replacement.nftMint, replacement.nftTokenAccount);
+        expect.fail("Should have rejected a never-minted parcel");
+      } catch (error) {
+        expect(error.message).to.include("NFTNeverMinted");
+      }
+    });
+  });
+
+  describe("NFT standard migration", () => {
+    const createToken2022Mint = async (freezeAuthority: PublicKey | null) => {
+      const newMint = await createMint(
+        connection,
+        provider.wallet.payer,
+        provider.wallet.publicKey,
+        freezeAuthority,
+        0,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const newTokenAccount = await createAccount(
+        connection,
+        provider.wallet.payer,
+        newMint,
+        provider.wallet.publicKey,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      return { newMint, newTokenAccount };
+    };
+
+    const migrate = (
+      ulpinId: string,
+      oldMint: PublicKey,
+      ownerOldTokenAccount: PublicKey,
+      newMint: PublicKey,
+      ownerNewTokenAccount: PublicKey
+    ) =>
+      program.methods
+        .migrateNftStandard(ulpinId, newMint)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          oldMint,
+          ownerOldTokenAccount,
+          newMint,
+          ownerNewTokenAccount,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          owner: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+    it("Burns the old NFT and mints a Token-2022 NFT to the same owner", async () => {
+      const ulpinId = "GJMIGRATE0000000001";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      const { newMint, newTokenAccount } = await createToken2022Mint(freezeAuthorityPDA);
+
+      await migrate(ulpinId, nftMint, nftTokenAccount, newMint, newTokenAccount);
+
+      expect(Number((await getMint(connection, nftMint)).supply)).to.equal(0);
+      const migrated = await getMint(connection, newMint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(migrated.supply)).to.equal(1);
+      expect(migrated.mintAuthority).to.be.null;
+      const holding = await getAccount(connection, newTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(holding.amount)).to.equal(1);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMint.toString()).to.equal(newMint.toString());
+    });
+
+    it("Freezes a migrated NFT through the Token-2022 program", async () => {
+      const ulpinId = "GJMIGRATE0000000002";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      const { newMint, newTokenAccount } = await createToken2022Mint(freezeAuthorityPDA);
+      await migrate(ulpinId, nftMint, nftTokenAccount, newMint, newTokenAccount);
+
+      await program.methods
+        .freezeLandNft(new anchor.BN(3600), Array(32).fill(0), 0, "Boundary dispute")
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          userTokenAccount: newTokenAccount,
+          nftMint: newMint,
+          freezeAuthority: freezeAuthorityPDA,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const holding = await getAccount(connection, newTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(holding.isFrozen).to.be.true;
+    });
+
+    it("Rejects a new mint with a different freeze authority", async () => {
+      const ulpinId = "GJMIGRATE0000000003";
+      const { nftMint, nftTokenAccount } = await prepareFreezableParcel(ulpinId);
+      const { newMint, newTokenAccount } = await createToken2022Mint(null);
+
+      try {
+        await migrate(ulpinId, nftMint, nftTokenAccount, newMint, newTokenAccount);
+        expect.fail("Should have rejected a mint that cannot be frozen");
+      } catch (error) {
+        expect(error.message).to.include("FreezeAuthorityMismatch");
+      }
+    });
+  });
+
+  describe("SOL fee path", () => {
+    it("Collects the mint fee in lamports", async () => {
+      const ulpinId = "GJSOLFEE00000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      const { nftMint } = await createNftMint();
+      const lamportsBefore = await connection.getBalance(treasuryPDA);
+      const treasuryBefore = await program.account.treasury.fetch(treasuryPDA);
+
+      await program.methods
+        .mintLandNftSol(ulpinId, "https://ipfs.io/ipfs/QmTestSol", 0)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          nftMint,
+          ownerMintRecord: findOwnerMintRecordPDA(provider.wallet.publicKey),
+          landUseRevenue: landUseRevenuePDA,
+          districtTreasury: findDistrictTreasuryPDA("Ahmedabad"),
+          feeConfig: feeConfigPDA,
+          exemptionAuthority: null,
+          user: provider.wallet.publicKey,
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const treasuryAfter = await program.account.treasury.fetch(treasuryPDA);
+      const collected = treasuryAfter.solFeesCollected.sub(treasuryBefore.solFeesCollected).toNumber();
+      expect(collected).to.be.greaterThan(0);
+      expect(await connection.getBalance(treasuryPDA)).to.equal(lamportsBefore + collected);
+      expect(treasuryAfter.totalFeesCollected.toNumber()).to.equal(treasuryBefore.totalFeesCollected.toNumber());
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMinted).to.be.true;
+    });
+
+    it("Withdraws lamport fees but keeps the treasury rent-exempt", async () => {
+      const recipient = anchor.web3.Keypair.generate().publicKey;
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(recipient, LAMPORTS_PER_SOL)
+      );
+      const withdrawSol = (amount: number) =>
+        program.methods
+          .withdrawSolFees(new anchor.BN(amount))
+          .accounts({
+            treasury: treasuryPDA,
+            destination: recipient,
+            authority: provider.wallet.publicKey,
+          })
+          .rpc();
+
+      const treasuryInfo = await connection.getAccountInfo(treasuryPDA);
+      const rentExempt = await connection.getMinimumBalanceForRentExemption(treasuryInfo.data.length);
+      const available = treasuryInfo.lamports - rentExempt;
+
+      try {
+        await withdrawSol(available + 1);
+        expect.fail("Should not withdraw into the rent-exempt reserve");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientTreasuryBalance");
+      }
+
+      const treasuryBefore = await program.account.treasury.fetch(treasuryPDA);
+      await withdrawSol(available);
+
+      expect(await connection.getBalance(treasuryPDA)).to.equal(rentExempt);
+      expect(await connection.getBalance(recipient)).to.equal(LAMPORTS_PER_SOL + available);
+      const treasuryAfter = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasuryAfter.solWithdrawn.sub(treasuryBefore.solWithdrawn).toNumber()).to.equal(available);
+    });
+  });
+
+  describe("land-use revenue", () => {
+    it("Attributes each mint fee to the parcel's land use", async () => {
+      const parcels = [
+        { ulpinId: "GJLANDUSEREV0000001", landUse: LandUse.agricultural },
+        { ulpinId: "GJLANDUSEREV0000002", landUse: LandUse.commercial },
+      ];
+
+      for (const { ulpinId, landUse } of parcels) {
+        await registerParcel(ulpinId, provider.wallet.publicKey, { landUse });
+        await verifyParcel(ulpinId);
+        const revenueBefore = await program.account.landUseRevenue.fetchNullable(landUseRevenuePDA);
+        const treasuryBefore = await program.account.treasury.fetch(treasuryPDA);
+
+        await mintParcel(ulpinId);
+
+        const revenueAfter = await program.account.landUseRevenue.fetch(landUseRevenuePDA);
+        const treasuryAfter = await program.account.treasury.fetch(treasuryPDA);
+        const fee = treasuryAfter.totalFeesCollected.sub(treasuryBefore.totalFeesCollected).toNumber();
+        revenueAfter.tokenFees.forEach((amount, index) => {
+          const before = revenueBefore ? revenueBefore.tokenFees[index].toNumber() : 0;
+          expect(amount.toNumber() - before).to.equal(index === landUse ? fee : 0);
+        });
+      }
+    });
+
+    it("Emits a snapshot of the breakdown", async () => {
+      const revenue = await program.account.landUseRevenue.fetch(landUseRevenuePDA);
+      let emitted: any = null;
+      const listener = program.addEventListener("LandUseRevenueSnapshot", (event) => {
+        emitted = event;
+      });
+
+      await program.methods
+        .emitLanduseRevenue()
+        .accounts({ landUseRevenue: landUseRevenuePDA })
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(emitted).to.not.be.null;
+      expect(emitted.tokenFees.map((amount) => amount.toNumber())).to.deep.equal(
+        revenue.tokenFees.map((amount) => amount.toNumber())
+      );
+    });
+  });
+
+  describe("fee withdrawal", () => {
+    const beneficiary = anchor.web3.Keypair.generate();
+    let destination: PublicKey;
+
+    before(async () => {
+      destination = await createAccount(connection, provider.wallet.payer, mint, beneficiary.publicKey);
+    });
+
+    it("Withdraws fees to a destination account", async () => {
+      const before = await program.account.treasury.fetch(treasuryPDA);
+
+      await program.methods
+        .withdrawFees(new anchor.BN(500))
+        .accounts({
+          treasury: treasuryPDA,
+          treasuryTokenAccount: treasuryTokenAccount,
+          destination,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const after = await program.account.treasury.fetch(treasuryPDA);
+      expect(Number((await getAccount(connection, destination)).amount)).to.equal(500);
+      expect(after.totalWithdrawn.toNumber()).to.equal(before.totalWithdrawn.toNumber() + 500);
+    });
+
+    it("Rejects withdrawing more than the treasury holds", async () => {
+      const balance = (await getAccount(connection, treasuryTokenAccount)).amount;
+
+      try {
+        await program.methods
+          .withdrawFees(new anchor.BN((balance + BigInt(1)).toString()))
+          .accounts({
+            treasury: treasuryPDA,
+            treasuryTokenAccount: treasuryTokenAccount,
+            destination,
+            authority: provider.wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have rejected an overdraw");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientTreasuryBalance");
+      }
+    });
+  });
+
+  describe("treasury balance reconciliation", () => {
+    it("Keeps collected minus withdrawn equal to the current balance", async () => {
+      const ulpinId = "GJRECONCILE00000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+
+      const afterMint = await program.account.treasury.fetch(treasuryPDA);
+      expect(afterMint.currentBalance.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .withdrawFees(new anchor.BN(100))
+        .accounts({
+          treasury: treasuryPDA,
+          treasuryTokenAccount: treasuryTokenAccount,
+          destination: userTokenAccount,
+          authority: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const treasury = await program.account.treasury.fetch(treasuryPDA);
+      expect(treasury.currentBalance.toNumber()).to.equal(afterMint.currentBalance.toNumber() - 100);
+      expect(treasury.totalFeesCollected.sub(treasury.totalWithdrawn).toNumber()).to.equal(
+        treasury.currentBalance.toNumber()
+      );
+    });
+  });
+
+  describe("ownership history", () => {
+    it("Records every transfer in order", async () => {
+      const ulpinId = "GJHISTORY0000000001";
+      const owners = [anchor.web3.Keypair.generate().publicKey, anchor.web3.Keypair.generate().publicKey];
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+
+      for (const owner of owners) {
+        await transferParcel(ulpinId, owner).rpc();
+      }
+
+      const history = await program.account.ownershipHistory.fetch(findOwnershipHistoryPDA(ulpinId));
+      expect(history.page).to.equal(0);
+      expect(history.entries.map((entry) => entry.owner.toString())).to.deep.equal(
+        owners.map((owner) => owner.toString())
+      );
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.ownershipHistoryCount).to.equal(2);
+    });
+
+    it("Rolls over to a new page once a page is full", async () => {
+      const ulpinId = "GJHISTORY0000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+
+      // Always hand the parcel back to the wallet so it can keep signing
+      for (let i = 0; i < 16; i++) {
+        await transferParcel(ulpinId, provider.wallet.publicKey).rpc();
+      }
+
+      try {
+        await transferParcel(ulpinId, provider.wallet.publicKey, 0).rpc();
+        expect.fail("Should have required the next history page");
+      } catch (error) {
+        expect(error.message).to.include("ConstraintSeeds");
+      }
+
+      await transferParcel(ulpinId, provider.wallet.publicKey, 1).rpc();
+
+      const firstPage = await program.account.ownershipHistory.fetch(findOwnershipHistoryPDA(ulpinId, 0));
+      const secondPage = await program.account.ownershipHistory.fetch(findOwnershipHistoryPDA(ulpinId, 1));
+      expect(firstPage.entries.length).to.equal(16);
+      expect(secondPage.page).to.equal(1);
+      expect(secondPage.entries.length).to.equal(1);
+    });
+  });
+
+  describe("parcel bounding box", () => {
+    const registerWithBbox = (ulpinId: string, box: typeof bbox) =>
+      program.methods
+        .registerLandParcel(
+          ulpinId,
+          new anchor.BN(1000),
+          "Ahmedabad",
+          "City",
+          "Village1",
+          provider.wallet.publicKey,
+          documentHash,
+          box.minLat,
+          box.minLon,
+          box.maxLat,
+          box.maxLon,
+          LandUse.agricultural
+        )
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          jurisdiction: jurisdictionPDA,
+          authority: provider.wallet.publicKey,
+          districtRules: findDistrictRulesPDA("Ahmedabad"),
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("Stores the bounding box and includes it in the registration event", async () => {
+      const ulpinId = "GJBBOX0000000000001";
+
+      let registered: any = null;
+      const listener = program.addEventListener("LandParcelRegistered", (event) => {
+        if (event.ulpinId === ulpinId) {
+          registered = event;
+        }
+      });
+      await registerWithBbox(ulpinId, bbox);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.bbox).to.deep.equal(bbox);
+      expect(registered).to.not.be.null;
+      expect(registered.bbox).to.deep.equal(bbox);
+    });
+
+    it("Rejects an inverted bounding box", async () => {
+      try {
+        await registerWithBbox("GJBBOX0000000000002", { ...bbox, minLat: bbox.maxLat, maxLat: bbox.minLat });
+        expect.fail("Should have rejected min_lat > max_lat");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCoordinates");
+      }
+    });
+
+    it("Rejects coordinates outside Gujarat", async () => {
+      // Mumbai
+      const outside = { minLat: 19_000_000, minLon: 72_800_000, maxLat: 19_100_000, maxLon: 72_900_000 };
+      try {
+        await registerWithBbox("GJBBOX0000000000003", outside);
+        expect.fail("Should have rejected a box outside Gujarat");
+      } catch (error) {
+        expect(error.message).to.include("OutsideJurisdiction");
+      }
+    });
+
+    it("Registers only within a configured jurisdiction", async () => {
+      const setJurisdiction = (bounds: typeof bbox) =>
+        program.methods
+          .setJurisdiction(bounds)
+          .accounts({
+            jurisdiction: jurisdictionPDA,
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      // Ahmedabad district
+      await setJurisdiction({ minLat: 22_500_000, minLon: 72_000_000, maxLat: 23_500_000, maxLon: 73_000_000 });
+
+      try {
+        await registerWithBbox("GJBBOX0000000000004", bbox);
+        const landParcel = await program.account.landParcel.fetch(findLandParcelPDA("GJBBOX0000000000004"));
+        expect(landParcel.bbox).to.deep.equal(bbox);
+
+        // Surat, inside Gujarat but outside the configured box
+        const surat = { minLat: 21_100_000, minLon: 72_800_000, maxLat: 21_200_000, maxLon: 72_900_000 };
+        try {
+          await registerWithBbox("GJBBOX0000000000005", surat);
+          expect.fail("Should have rejected a box outside the configured jurisdiction");
+        } catch (error) {
+          expect(error.message).to.include("OutsideJurisdiction");
+        }
+      } finally {
+        await setJurisdiction({ minLat: 20_000_000, minLon: 68_000_000, maxLat: 24_800_000, maxLon: 74_600_000 });
+      }
+    });
+  });
+
+  describe("parcel disputes", () => {
+    const claimant = anchor.web3.Keypair.generate();
+
+    const openDispute = (ulpinId: string, reason: string) =>
+      program.methods
+        .openDispute(reason)
+        .accounts({ landParcel: findLandParcelPDA(ulpinId), claimant: claimant.publicKey })
+        .signers([claimant])
+        .rpc();
+
+    const resolveDispute = (ulpinId: string, upheld: boolean) =>
+      program.methods
+        .resolveDispute(upheld)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Blocks minting while a dispute is open", async () => {
+      const ulpinId = "GJDISPUTE0000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await openDispute(ulpinId, "Boundary encroachment");
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.underDispute).to.be.true;
+      expect(landParcel.disputeClaimant.toString()).to.equal(claimant.publicKey.toString());
+
+      try {
+        await mintParcel(ulpinId);
+        expect.fail("Should have blocked minting a disputed parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelUnderDispute");
+      }
+
+      await resolveDispute(ulpinId, false);
+      await mintParcel(ulpinId);
+    });
+
+    it("Blocks ownership transfers until the authority resolves the dispute", async () => {
+      const ulpinId = "GJDISPUTE0000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+      await openDispute(ulpinId, "Competing sale deed");
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have blocked transferring a disputed parcel");
+      } catch (error) {
+        expect(error.message).to.include("ParcelUnderDispute");
+      }
+
+      let resolved: any = null;
+      const listener = program.addEventListener("DisputeResolved", (event) => {
+        resolved = event;
+      });
+      await resolveDispute(ulpinId, true);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(resolved.upheld).to.be.true;
+      expect(resolved.claimant.toString()).to.equal(claimant.publicKey.toString());
+      await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+    });
+
+    it("Rejects resolving a parcel with no open dispute", async () => {
+      const ulpinId = "GJDISPUTE0000000003";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      try {
+        await resolveDispute(ulpinId, false);
+        expect.fail("Should have rejected resolving without a dispute");
+      } catch (error) {
+        expect(error.message).to.include("NoOpenDispute");
+      }
+    });
+  });
+
+  describe("NFT mint validation", () => {
+    it("Rejects a fungible mint", async () => {
+      const ulpinId = "GJNOTNFT00000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+
+      const fungibleMint = await createMint(connection, provider.wallet.payer, provider.wallet.publicKey, null, 9);
+      try {
+        await mintParcel(ulpinId, provider.wallet.publicKey, { nftMint: fungibleMint });
+        expect.fail("Should have rejected a decimals-9 mint");
+      } catch (error) {
+        expect(error.message).to.include("NotAnNFT");
+      }
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.nftMinted).to.be.false;
+    });
+
+    it("Rejects a mint that can still issue tokens", async () => {
+      const ulpinId = "GJNOTNFT00000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+
+      const openMint = await createMint(connection, provider.wallet.payer, provider.wallet.publicKey, null, 0);
+      const tokenAccount = await createAccount(connection, provider.wallet.payer, openMint, provider.wallet.publicKey);
+      await mintTo(connection, provider.wallet.payer, openMint, tokenAccount, provider.wallet.payer, 1);
+      try {
+        await mintParcel(ulpinId, provider.wallet.publicKey, { nftMint: openMint });
+        expect.fail("Should have rejected a mint with a live mint authority");
+      } catch (error) {
+        expect(error.message).to.include("NotAnNFT");
+      }
+    });
+  });
+
+  describe("district statistics", () => {
+    it("Counts registrations, verifications and mints per district", async () => {
+      const ulpinId = "GJSTATS000000000001";
+      const statsPDA = findDistrictStatsPDA("Kutch");
+      const countsOf = async () => {
+        const stats = await program.account.districtStats.fetchNullable(statsPDA);
+        return stats
+          ? [stats.registeredCount.toNumber(), stats.verifiedCount.toNumber(), stats.mintedCount.toNumber()]
+          : [0, 0, 0];
+      };
+
+      const before = await countsOf();
+      await registerParcel(ulpinId, provider.wallet.publicKey, { district: "Kutch" });
+      expect(await countsOf()).to.deep.equal([before[0] + 1, before[1], before[2]]);
+
+      await verifyParcel(ulpinId);
+      expect(await countsOf()).to.deep.equal([before[0] + 1, before[1] + 1, before[2]]);
+
+      await mintParcel(ulpinId);
+      expect(await countsOf()).to.deep.equal([before[0] + 1, before[1] + 1, before[2] + 1]);
+
+      const ahmedabad = await program.account.districtStats.fetch(findDistrictStatsPDA("Ahmedabad"));
+      expect(ahmedabad.registeredCount.toNumber()).to.be.greaterThan(0);
+    });
+  });
+
+  describe("idempotent verification", () => {
+    const ensureVerified = async (ulpinId: string) =>
+      program.methods
+        .ensureVerified(ulpinId)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          owner: null,
+          accreditedFirm: null,
+          verificationState: findVerificationStatePDA(findLandParcelPDA(ulpinId)),
+          verifierRegistry: null,
+          districtStats: await districtStatsFor(ulpinId),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("Verifies once and treats retries as a no-op", async () => {
+      const ulpinId = "GJENSURE00000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      let verifiedEvents = 0;
+      const listener = program.addEventListener("LandParcelVerified", (event) => {
+        if (event.ulpinId === ulpinId) {
+          verifiedEvents += 1;
+        }
+      });
+      await ensureVerified(ulpinId);
+      await ensureVerified(ulpinId);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(verifiedEvents).to.equal(1);
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.isVerified).to.be.true;
+    });
+
+    it("Keeps verify_land_parcel strict", async () => {
+      const ulpinId = "GJENSURE00000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await ensureVerified(ulpinId);
+
+      try {
+        await verifyParcel(ulpinId);
+        expect.fail("Should have rejected a second strict verification");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyVerified");
+      }
+    });
+  });
+
+  describe("batch verification", () => {
+    // Parcel accounts go in remainingAccounts, one per id and in the same order
+    const verifyBatch = (ulpinIds: string[], accountOrder: string[] = ulpinIds) =>
+      program.methods
+        .verifyParcelsBatch("Ahmedabad", ulpinIds)
+        .accounts({
+          treasury: treasuryPDA,
+          verifierRegistry: null,
+          districtStats: findDistrictStatsPDA("Ahmedabad"),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          accountOrder.map((ulpinId) => ({ pubkey: findLandParcelPDA(ulpinId), isWritable: true, isSigner: false }))
+        )
+        .rpc();
+
+    it("Verifies unverified parcels and reports the ones already verified", async () => {
+      const ulpinIds = ["GJBATCHVERIFY000001", "GJBATCHVERIFY000002", "GJBATCHVERIFY000003"];
+      for (const ulpinId of ulpinIds) {
+        await registerParcel(ulpinId, provider.wallet.publicKey);
+      }
+      await verifyParcel(ulpinIds[1]);
+
+      const statsBefore = await program.account.districtStats.fetch(findDistrictStatsPDA("Ahmedabad"));
+
+      let batch: any = null;
+      const listener = program.addEventListener("ParcelsBatchVerified", (event) => {
+        batch = event;
+      });
+      await verifyBatch(ulpinIds);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(batch.verifiedCount).to.equal(2);
+      expect(batch.skippedCount).to.equal(1);
+      expect(batch.skipped).to.deep.equal([ulpinIds[1]]);
+      const statsAfter = await program.account.districtStats.fetch(findDistrictStatsPDA("Ahmedabad"));
+      expect(statsAfter.verifiedCount.toNumber()).to.equal(statsBefore.verifiedCount.toNumber() + 2);
+      for (const ulpinId of ulpinIds) {
+        const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+        expect(landParcel.isVerified).to.be.true;
+      }
+    });
+
+    it("Rejects parcel accounts passed out of order", async () => {
+      const ulpinIds = ["GJBATCHVERIFY000004", "GJBATCHVERIFY000005"];
+      for (const ulpinId of ulpinIds) {
+        await registerParcel(ulpinId, provider.wallet.publicKey);
+      }
+
+      try {
+        await verifyBatch(ulpinIds, [...ulpinIds].reverse());
+        expect.fail("Should have rejected mismatched parcel accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidBatchAccounts");
+      }
+    });
+  });
+
+  describe("district minimum plot size", () => {
+    const setMinimum = (district: string, minAreaSqm: number) =>
+      program.methods
+        .setDistrictRules(district, new anchor.BN(minAreaSqm))
+        .accounts({
+          districtRules: findDistrictRulesPDA(district),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    before(async () => {
+      await setMinimum("Dang", 500);
+    });
+
+    it("Rejects plots below the district minimum", async () => {
+      try {
+        await registerParcel("GJMINAREA0000000001", provider.wallet.publicKey, { areaSqm: 499, district: "Dang" });
+        expect.fail("Should have rejected an undersized plot");
+      } catch (error) {
+        expect(error.message).to.include("AreaBelowDistrictMinimum");
+      }
+    });
+
+    it("Accepts plots at the minimum and ignores districts without rules", async () => {
+      await registerParcel("GJMINAREA0000000002", provider.wallet.publicKey, { areaSqm: 500, district: "Dang" });
+      await registerParcel("GJMINAREA0000000003", provider.wallet.publicKey, { areaSqm: 1, district: "Navsari" });
+    });
+
+    it("Applies the minimum to batch registrations", async () => {
+      const ulpinIds = ["GJMINAREA0000000004", "GJMINAREA0000000005"];
+      try {
+        await program.methods
+          .registerLandParcelsBatch(
+            "Dang",
+            ulpinIds.map((ulpinId, i) => ({
+              ulpinId,
+              areaSqm: new anchor.BN(i === 0 ? 600 : 499),
+              district: "Dang",
+              taluka: "City",
+              village: "Village1",
+              owner: provider.wallet.publicKey,
+              documentHash,
+              bbox,
+              landUse: { agricultural: {} },
+            }))
+          )
+          .accounts({
+            treasury: treasuryPDA,
+            sanctionsList: sanctionsListPDA,
+            jurisdiction: jurisdictionPDA,
+            districtRules: findDistrictRulesPDA("Dang"),
+            districtStats: findDistrictStatsPDA("Dang"),
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            ulpinIds.map((ulpinId) => ({ pubkey: findLandParcelPDA(ulpinId), isWritable: true, isSigner: false }))
+          )
+          .rpc();
+        expect.fail("Should have rejected an undersized plot in the batch");
+      } catch (error) {
+        expect(error.message).to.include("AreaBelowDistrictMinimum");
+      }
+    });
+
+    it("Applies the minimum to subdivided plots", async () => {
+      const parentUlpin = "GJMINAREA0000000006";
+      const children = [
+        { ulpinId: "GJMINAREA0000000007", areaSqm: 600 },
+        { ulpinId: "GJMINAREA0000000008", areaSqm: 400 },
+      ];
+      await registerParcel(parentUlpin, provider.wallet.publicKey, { areaSqm: 1000, district: "Dang" });
+
+      try {
+        await program.methods
+          .subdivideParcel(
+            children.map((child) => ({
+              ulpinId: child.ulpinId,
+              areaSqm: new anchor.BN(child.areaSqm),
+              owner: provider.wallet.publicKey,
+              documentHash,
+            }))
+          )
+          .accounts({
+            parentParcel: findLandParcelPDA(parentUlpin),
+            treasury: treasuryPDA,
+            sanctionsList: sanctionsListPDA,
+            jurisdiction: jurisdictionPDA,
+            districtRules: findDistrictRulesPDA("Dang"),
+            districtStats: findDistrictStatsPDA("Dang"),
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            children.map((child) => ({ pubkey: findLandParcelPDA(child.ulpinId), isWritable: true, isSigner: false }))
+          )
+          .rpc();
+        expect.fail("Should have rejected an undersized child plot");
+      } catch (error) {
+        expect(error.message).to.include("AreaBelowDistrictMinimum");
+      }
+    });
+  });
+
+  describe("land-use classification", () => {
+    const reclassify = (ulpinId: string, newUse: number) =>
+      program.methods
+        .reclassifyParcel(newUse)
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    it("Stores the class given at registration and lets the authority change it", async () => {
+      const ulpinId = "GJLANDUSE0000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey, { landUse: LandUse.residential });
+      let landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.landUse).to.deep.equal({ residential: {} });
+
+      await reclassify(ulpinId, LandUse.commercial);
+      landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.landUse).to.deep.equal({ commercial: {} });
+    });
+
+    it("Rejects unknown land-use codes", async () => {
+      try {
+        await registerParcel("GJLANDUSE0000000002", provider.wallet.publicKey, { landUse: 6 });
+        expect.fail("Should have rejected land-use code 6");
+      } catch (error) {
+        expect(error.message).to.include("InvalidLandUse");
+      }
+    });
+  });
+
+  describe("land-use fee multipliers", () => {
+    const setMultipliers = (multipliers: number[]) =>
+      program.methods
+        .setClassFeeMultipliers(multipliers)
+        .accounts({
+          feeConfig: feeConfigPDA,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    const mintAndMeasureFee = async (ulpinId: string, landUse: number) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey, { areaSqm: 1000, landUse });
+      await verifyParcel(ulpinId);
+      const before = await getAccount(connection, treasuryTokenAccount);
+      await mintParcel(ulpinId);
+      const after = await getAccount(connection, treasuryTokenAccount);
+      return Number(after.amount) - Number(before.amount);
+    };
+
+    after(async () => {
+      await setMultipliers(Array(6).fill(10000));
+    });
+
+    it("Defaults every class to 1x", async () => {
+      const feeConfig = await program.account.feeConfig.fetch(feeConfigPDA);
+      expect(feeConfig.classMultipliersBps).to.deep.equal(Array(6).fill(10000));
+    });
+
+    it("Scales the area fee by the parcel's class", async () => {
+      const { baseFee, perSqmFee } = await program.account.feeConfig.fetch(feeConfigPDA);
+      await setMultipliers([5000, 10000, 20000, 15000, 10000, 10000]);
+
+      const areaFee = 1000 * perSqmFee.toNumber();
+      expect(await mintAndMeasureFee("GJCLASSFEE000000001", LandUse.agricultural)).to.equal(
+        baseFee.toNumber() + areaFee / 2
+      );
+      expect(await mintAndMeasureFee("GJCLASSFEE000000002", LandUse.commercial)).to.equal(
+        baseFee.toNumber() + areaFee * 2
+      );
+    });
+  });
+
+  describe("escrowed ownership transfer", () => {
+    const buyer = anchor.web3.Keypair.generate();
+    const price = LAMPORTS_PER_SOL / 2;
+
+    const findPendingTransferPDA = (ulpinId: string): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_transfer"), findLandParcelPDA(ulpinId).toBuffer()],
+        program.programId
+      )[0];
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    };
+
+    const propose = (ulpinId: string) =>
+      program.methods
+        .proposeTransfer(buyer.publicKey, new anchor.BN(price))
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          villageConfig: findVillageConfigPDA(),
+          pendingTransfer: findPendingTransferPDA(ulpinId),
+          seller: provider.wallet.publicKey,
+          buyer: buyer.publicKey,
+          protectionAuthority: null,
+          panchayat: null,
+          karta: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+    const accept = (ulpinId: string) =>
+      program.methods
+        .acceptTransfer()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          villageConfig: findVillageConfigPDA(),
+          pendingTransfer: findPendingTransferPDA(ulpinId),
+          ownershipHistory: findOwnershipHistoryPDA(ulpinId),
+          seller: provider.wallet.publicKey,
+          buyer: buyer.publicKey,
+          protectionAuthority: null,
+          panchayat: null,
+          karta: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(buyer.publicKey, 3 * LAMPORTS_PER_SOL)
+      );
+    });
+
+    it("Pays the seller and hands over the parcel when the buyer accepts", async () => {
+      const ulpinId = "GJESCROW00000000001";
+      await prepareMintedParcel(ulpinId);
+      await propose(ulpinId);
+
+      expect((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).transferPending).to.be.true;
+      const sellerBefore = await connection.getBalance(provider.wallet.publicKey);
+
+      await accept(ulpinId);
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(buyer.publicKey.toString());
+      expect(landParcel.transferPending).to.be.false;
+      const history = await program.account.ownershipHistory.fetch(findOwnershipHistoryPDA(ulpinId));
+      expect(history.entries.map((entry) => entry.owner.toString())).to.deep.equal([buyer.publicKey.toString()]);
+      // The wallet also pays the transaction fee
+      const sellerAfter = await connection.getBalance(provider.wallet.publicKey);
+      expect(sellerAfter - sellerBefore).to.be.closeTo(price, 10_000);
+      expect(await program.account.pendingTransfer.fetchNullable(findPendingTransferPDA(ulpinId))).to.be.null;
+    });
+
+    it("Rejects a second proposal and direct transfers while one is pending", async () => {
+      const ulpinId = "GJESCROW00000000002";
+      await prepareMintedParcel(ulpinId);
+      await propose(ulpinId);
+
+      try {
+        await propose(ulpinId);
+        expect.fail("Should have rejected a double proposal");
+      } catch (error) {
+        expect(error.message).to.include("TransferAlreadyPending");
+      }
+
+      try {
+        await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+        expect.fail("Should have blocked a direct transfer during escrow");
+      } catch (error) {
+        expect(error.message).to.include("TransferAlreadyPending");
+      }
+    });
+
+    it("Refunds the buyer when the sale is cancelled", async () => {
+      const ulpinId = "GJESCROW00000000003";
+      await prepareMintedParcel(ulpinId);
+      const buyerBefore = await connection.getBalance(buyer.publicKey);
+      await propose(ulpinId);
+
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          pendingTransfer: findPendingTransferPDA(ulpinId),
+          buyer: buyer.publicKey,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(landParcel.transferPending).to.be.false;
+      // Only the proposal's transaction fee is lost
+      expect(await connection.getBalance(buyer.publicKey)).to.be.greaterThan(buyerBefore - LAMPORTS_PER_SOL / 100);
+    });
+
+    it("Holds acceptance until the transfer delay has passed", async () => {
+      const ulpinId = "GJESCROW00000000004";
+      const setDelay = (seconds: number) =>
+        program.methods
+          .setTransferDelay(new anchor.BN(seconds))
+          .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+          .rpc();
+      await prepareMintedParcel(ulpinId);
+      await setDelay(2);
+
+      try {
+        await propose(ulpinId);
+        try {
+          await accept(ulpinId);
+          expect.fail("Should have held the sale during the challenge window");
+        } catch (error) {
+          expect(error.message).to.include("TransferDelayNotElapsed");
+        }
+
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        await accept(ulpinId);
+      } finally {
+        await setDelay(0);
+      }
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(buyer.publicKey.toString());
+    });
+
+    it("Rechecks sanctions when the buyer accepts", async () => {
+      const ulpinId = "GJESCROW00000000005";
+      const updateSanctions = (method: "addSanctionedParty" | "removeSanctionedParty") =>
+        program.methods[method](buyer.publicKey)
+          .accounts({ sanctionsList: sanctionsListPDA, treasury: treasuryPDA, authority: provider.wallet.publicKey })
+          .rpc();
+      await prepareMintedParcel(ulpinId);
+      await propose(ulpinId);
+      await updateSanctions("addSanctionedParty");
+
+      try {
+        await accept(ulpinId);
+        expect.fail("Should have rejected a buyer sanctioned after the proposal");
+      } catch (error) {
+        expect(error.message).to.include("SanctionedParty");
+      } finally {
+        await updateSanctions("removeSanctionedParty");
+      }
+    });
+  });
+
+  describe("time-locked ownership transfers", () => {
+    const setDelay = (seconds: number) =>
+      program.methods
+        .setTransferDelay(new anchor.BN(seconds))
+        .accounts({ treasury: treasuryPDA, authority: provider.wallet.publicKey })
+        .rpc();
+
+    const prepareMintedParcel = async (ulpinId: string) => {
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+    };
+
+    const finalize = (ulpinId: string) =>
+      program.methods
+        .finalizeOwnership()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          treasury: treasuryPDA,
+          sanctionsList: sanctionsListPDA,
+          villageConfig: findVillageConfigPDA(),
+          ownershipHistory: findOwnershipHistoryPDA(ulpinId),
+          payer: provider.wallet.publicKey,
+          protectionAuthority: null,
+          panchayat: null,
+          karta: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    before(async () => {
+      await setDelay(2);
+    });
+
+    after(async () => {
+      await setDelay(0);
+    });
+
+    it("Stages the change and applies it only after the delay", async () => {
+      const ulpinId = "GJTIMELOCK000000001";
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      await prepareMintedParcel(ulpinId);
+      await transferParcel(ulpinId, newOwner).rpc();
+
+      let landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(landParcel.pendingOwner.toString()).to.equal(newOwner.toString());
+
+      try {
+        await finalize(ulpinId);
+        expect.fail("Should have rejected finalizing inside the challenge window");
+      } catch (error) {
+        expect(error.message).to.include("TransferDelayNotElapsed");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await finalize(ulpinId);
+
+      landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+      expect(landParcel.pendingOwner).to.be.null;
+    });
+
+    it("Refuses to subdivide a parcel with a staged change", async () => {
+      const ulpinId = "GJTIMELOCK000000003";
+      await prepareMintedParcel(ulpinId);
+      await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+
+      const children = ["GJTIMELOCK000000004", "GJTIMELOCK000000005"];
+      try {
+        await program.methods
+          .subdivideParcel(
+            children.map((childUlpin) => ({
+              ulpinId: childUlpin,
+              areaSqm: new anchor.BN(500),
+              owner: provider.wallet.publicKey,
+              documentHash,
+            }))
+          )
+          .accounts({
+            parentParcel: findLandParcelPDA(ulpinId),
+            treasury: treasuryPDA,
+            sanctionsList: sanctionsListPDA,
+            jurisdiction: jurisdictionPDA,
+            districtRules: findDistrictRulesPDA("Ahmedabad"),
+            districtStats: findDistrictStatsPDA("Ahmedabad"),
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            children.map((childUlpin) => ({
+              pubkey: findLandParcelPDA(childUlpin),
+              isWritable: true,
+              isSigner: false,
+            }))
+          )
+          .rpc();
+        expect.fail("Should have refused to subdivide around a staged change");
+      } catch (error) {
+        expect(error.message).to.include("TransferAlreadyPending");
+      }
+    });
+
+    it("Lets the current owner contest a staged change", async () => {
+      const ulpinId = "GJTIMELOCK000000002";
+      await prepareMintedParcel(ulpinId);
+      await transferParcel(ulpinId, anchor.web3.Keypair.generate().publicKey).rpc();
+
+      await program.methods
+        .contestOwnershipChange()
+        .accounts({ landParcel: findLandParcelPDA(ulpinId), owner: provider.wallet.publicKey })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(provider.wallet.publicKey.toString());
+      expect(landParcel.pendingOwner).to.be.null;
+
+      try {
+        await finalize(ulpinId);
+        expect.fail("Should have nothing left to finalize");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingOwnershipChange");
+      }
+    });
+  });
+
+  describe("account versioning", () => {
+    it("Stamps new treasury and parcel accounts with the current version", async () => {
+      const ulpinId = "GJVERSION000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      const treasury = await program.account.treasury.fetch(treasuryPDA);
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(treasury.version).to.equal(5);
+      expect(landParcel.version).to.equal(5);
+    });
+
+    it("Rejects migrating a parcel that is already current", async () => {
+      try {
+        await program.methods
+          .migrateLandParcel()
+          .accounts({
+            landParcel: findLandParcelPDA("GJVERSION000000001"),
+            treasury: treasuryPDA,
+            authority: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have had nothing to migrate");
+      } catch (error) {
+        expect(error.message).to.include("NothingToMigrate");
+      }
+    });
+  });
+
+  describe("land parcel realloc", () => {
+    const reallocParcel = (landParcel: PublicKey) =>
+      program.methods
+        .reallocLandParcel()
+        .accounts({
+          landParcel,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("Rejects growing a parcel that already has the current size", async () => {
+      const ulpinId = "GJREALLOC000000001";
+      const landParcelPDA = await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      try {
+        await reallocParcel(landParcelPDA);
+        expect.fail("Should have had nothing to grow");
+      } catch (error) {
+        expect(error.message).to.include("NothingToMigrate");
+      }
+    });
+
+    it("Grows a parcel written before the schema version was added", async () => {
+      // Loaded by the test validator from tests/fixtures/legacy_land_parcel.json
+      const legacyParcel = new PublicKey("8eD5R2nRizXYwGfB9M2GV868YEbuobFoHsV5S1WsmR9c");
+      const before = await provider.connection.getAccountInfo(legacyParcel);
+
+      await reallocParcel(legacyParcel);
+
+      const after = await provider.connection.getAccountInfo(legacyParcel);
+      expect(after.data.length).to.be.greaterThan(before.data.length);
+      expect(after.data.subarray(0, before.data.length).equals(before.data)).to.be.true;
+
+      const landParcel = await program.account.landParcel.fetch(legacyParcel);
+      expect(Buffer.from(landParcel.ulpinId).toString().replace(/\0+$/, "")).to.equal("GJLEGACY000000001");
+      expect(landParcel.areaSqm.toNumber()).to.equal(12000);
+      expect(landParcel.version).to.equal(0);
+    });
+
+    it("Migrates a grown legacy parcel to the current version", async () => {
+      const legacyParcel = new PublicKey("8eD5R2nRizXYwGfB9M2GV868YEbuobFoHsV5S1WsmR9c");
+
+      await program.methods
+        .migrateLandParcel()
+        .accounts({
+          landParcel: legacyParcel,
+          treasury: treasuryPDA,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const landParcel = await program.account.landParcel.fetch(legacyParcel);
+      expect(landParcel.version).to.equal(5);
+      expect(landParcel.activeLiens).to.equal(0);
+      expect(landParcel.lastMetadataUpdate.toNumber()).to.equal(0);
+      expect(landParcel.subsurfaceOwner.toString()).to.equal(landParcel.owner.toString());
+      expect(landParcel.occupancyStatus).to.deep.equal({ ownerOccupied: {} });
+      expect(landParcel.pendingOwner).to.be.null;
+    });
+
+    it("Rejects accounts that are not land parcels", async () => {
+      try {
+        await reallocParcel(treasuryPDA);
+        expect.fail("Should have rejected a non-parcel account");
+      } catch (error) {
+        expect(error.message).to.include("InvalidLandParcelAccount");
+      }
+    });
+  });
+
+  describe("liens", () => {
+    const lender = anchor.web3.Keypair.generate();
+    const secondLender = anchor.web3.Keypair.generate();
+    const amount = new anchor.BN(5_000_000);
+
+    const findLienPDA = (ulpinId: string, lenderKey: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("lien"), findLandParcelPDA(ulpinId).toBuffer(), lenderKey.toBuffer()],
+        program.programId
+      )[0];
+
+    const registerLien = (
+      ulpinId: string,
+      maturity = Math.floor(Date.now() / 1000) + 86_400,
+      lienLender = lender
+    ) =>
+      program.methods
+        .registerLien(lienLender.publicKey, amount, new anchor.BN(maturity))
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          lien: findLienPDA(ulpinId, lienLender.publicKey),
+          owner: provider.wallet.publicKey,
+          lender: lienLender.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([lienLender])
+        .rpc();
+
+    const releaseLien = (ulpinId: string, lienLender = lender) =>
+      program.methods
+        .releaseLien()
+        .accounts({
+          landParcel: findLandParcelPDA(ulpinId),
+          lien: findLienPDA(ulpinId, lienLender.publicKey),
+          lender: lienLender.publicKey,
+        })
+        .signers([lienLender])
+        .rpc();
+
+    before(async () => {
+      for (const lienLender of [lender, secondLender]) {
+        await connection.confirmTransaction(
+          await provider.connection.requestAirdrop(lienLender.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+    });
+
+    it("Blocks transfers while a lien is active and lifts the block on release", async () => {
+      const ulpinId = "GJLIEN0000000000001";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+      await registerLien(ulpinId);
+
+      const lien = await program.account.lien.fetch(findLienPDA(ulpinId, lender.publicKey));
+      expect(lien.lender.toString()).to.equal(lender.publicKey.toString());
+      expect(lien.amount.toNumber()).to.equal(amount.toNumber());
+      expect((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).activeLiens).to.equal(1);
+
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      try {
+        await transferParcel(ulpinId, newOwner).rpc();
+        expect.fail("Should have blocked the transfer");
+      } catch (error) {
+        expect(error.message).to.include("ActiveLienExists");
+      }
+
+      await releaseLien(ulpinId);
+      expect((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).activeLiens).to.equal(0);
+      expect(await connection.getAccountInfo(findLienPDA(ulpinId, lender.publicKey))).to.be.null;
+
+      await transferParcel(ulpinId, newOwner).rpc();
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Ranks concurrent liens and blocks transfers until every one is released", async () => {
+      const ulpinId = "GJLIEN0000000000010";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await mintParcel(ulpinId);
+      await registerLien(ulpinId);
+      await registerLien(ulpinId, undefined, secondLender);
+
+      const first = await program.account.lien.fetch(findLienPDA(ulpinId, lender.publicKey));
+      const second = await program.account.lien.fetch(findLienPDA(ulpinId, secondLender.publicKey));
+      expect(first.priority).to.equal(1);
+      expect(second.priority).to.equal(2);
+      expect((await program.account.landParcel.fetch(findLandParcelPDA(ulpinId))).activeLiens).to.equal(2);
+
+      await releaseLien(ulpinId);
+      const newOwner = anchor.web3.Keypair.generate().publicKey;
+      try {
+        await transferParcel(ulpinId, newOwner).rpc();
+        expect.fail("Should have blocked the transfer while the second lien is active");
+      } catch (error) {
+        expect(error.message).to.include("ActiveLienExists");
+      }
+
+      await releaseLien(ulpinId, secondLender);
+      await transferParcel(ulpinId, newOwner).rpc();
+      const landParcel = await program.account.landParcel.fetch(findLandParcelPDA(ulpinId));
+      expect(landParcel.owner.toString()).to.equal(newOwner.toString());
+    });
+
+    it("Blocks minting while a lien is active", async () => {
+      const ulpinId = "GJLIEN0000000000002";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+      await verifyParcel(ulpinId);
+      await registerLien(ulpinId);
+
+      try {
+        await mintParcel(ulpinId);
+        expect.fail("Should have blocked minting");
+      } catch (error) {
+        expect(error.message).to.include("ActiveLienExists");
+      }
+    });
+
+    it("Blocks subdividing a parcel with an active lien", async () => {
+      const ulpinId = "GJLIEN0000000000004";
+      await registerParcel(ulpinId, provider.wallet.publicKey, { areaSqm: 1000 });
+      await registerLien(ulpinId);
+
+      const children = ["GJLIEN0000000000005", "GJLIEN0000000000006"];
+      try {
+        await program.methods
+          .subdivideParcel(
+            children.map((childUlpin) => ({
+              ulpinId: childUlpin,
+              areaSqm: new anchor.BN(500),
+              owner: provider.wallet.publicKey,
+              documentHash,
+            }))
+          )
+          .accounts({
+            parentParcel: findLandParcelPDA(ulpinId),
+            treasury: treasuryPDA,
+            sanctionsList: sanctionsListPDA,
+            jurisdiction: jurisdictionPDA,
+            districtRules: findDistrictRulesPDA("Ahmedabad"),
+            districtStats: findDistrictStatsPDA("Ahmedabad"),
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            children.map((childUlpin) => ({
+              pubkey: findLandParcelPDA(childUlpin),
+              isWritable: true,
+              isSigner: false,
+            }))
+          )
+          .rpc();
+        expect.fail("Should have blocked the subdivision");
+      } catch (error) {
+        expect(error.message).to.include("ActiveLienExists");
+      }
+    });
+
+    it("Blocks merging a parcel with an active lien", async () => {
+      const sources = ["GJLIEN0000000000007", "GJLIEN0000000000008"];
+      for (const ulpinId of sources) {
+        await registerParcel(ulpinId, provider.wallet.publicKey);
+        await verifyParcel(ulpinId);
+      }
+      await registerLien(sources[1]);
+
+      const resultUlpin = "GJLIEN0000000000009";
+      try {
+        await program.methods
+          .mergeParcels(resultUlpin, "Ahmedabad", sources, documentHash)
+          .accounts({
+            resultParcel: findLandParcelPDA(resultUlpin),
+            treasury: treasuryPDA,
+            sanctionsList: sanctionsListPDA,
+            jurisdiction: jurisdictionPDA,
+            districtRules: findDistrictRulesPDA("Ahmedabad"),
+            districtStats: findDistrictStatsPDA("Ahmedabad"),
+            authority: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            sources.map((ulpinId) => ({
+              pubkey: findLandParcelPDA(ulpinId),
+              isWritable: true,
+              isSigner: false,
+            }))
+          )
+          .rpc();
+        expect.fail("Should have blocked the merge");
+      } catch (error) {
+        expect(error.message).to.include("ActiveLienExists");
+      }
+    });
+
+    it("Rejects liens that have already matured", async () => {
+      const ulpinId = "GJLIEN0000000000003";
+      await registerParcel(ulpinId, provider.wallet.publicKey);
+
+      try {
+        await registerLien(ulpinId, Math.floor(Date.now() / 1000) - 60);
+        expect.fail("Should have rejected a past maturity");
+      } catch (error) {
+        expect(error.message).to.include("InvalidLienMaturity");
+      }
+    });
+  });
 });